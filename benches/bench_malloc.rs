@@ -1,7 +1,7 @@
-///
-/// This test aims to verify there's no performance problem with
-/// huge buffers on small chunks (10 octets)
-///
+//!
+//! This test aims to verify there's no performance problem with
+//! huge buffers on small chunks (10 octets)
+//!
 
 #[path = "common/mod.rs"]
 mod common;
@@ -1,7 +1,7 @@
-//
-/// This test aims to verify there's no performance problem with
-/// huge buffers on small chunks (10 octets)
-///
+//!
+//! This test aims to verify there's no performance problem with
+//! huge buffers on small chunks (10 octets)
+//!
 
 #[path = "common/mod.rs"]
 mod common;
@@ -69,7 +69,7 @@ fn set_one_cpu() {
 
     if let Some(core_id) = core_ids.first() {
         let result = core_affinity::set_for_current(*core_id);
-        if false == result {
+        if !result {
             panic!("Can't attach to one thread only")
         }
     }
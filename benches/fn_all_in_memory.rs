@@ -1,5 +1,3 @@
-#[path = "common/mod.rs"]
-mod common;
 use std::io::Read;
 
 use crate::common::stream_generator::*;
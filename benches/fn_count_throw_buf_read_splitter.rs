@@ -1,8 +1,6 @@
 use buf_read_splitter::{BufReadSplitter, Options, SimpleMatcher};
 use std::io::Read;
 
-#[path = "common/mod.rs"]
-mod common;
 use crate::common::stream_generator::*;
 
 pub fn count_throw_buf_read_splitter(total_length: usize, buf_size: usize, content_len: usize) {
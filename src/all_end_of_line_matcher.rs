@@ -1,6 +1,7 @@
 use crate::MatchResult;
 use crate::Matcher;
 
+#[derive(Clone)]
 pub struct AllEndOfLineMatcher {
     prev_char: u8,
 }
@@ -9,6 +10,11 @@ impl AllEndOfLineMatcher {
         Self { prev_char: 0 }
     }
 }
+impl Default for AllEndOfLineMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 impl Matcher for AllEndOfLineMatcher {
     // This function is called at each byte read
     //   `el_buf` contains the value of the byte
@@ -40,4 +46,9 @@ impl Matcher for AllEndOfLineMatcher {
             panic!("We can't reach this code since we just manage 2 positions")
         }
     }
+
+    // This function is called to skip the bytes before the next end of line
+    fn skip(&mut self, buf: &[u8]) -> usize {
+        memchr::memchr2(b'\r', b'\n', buf).unwrap_or(buf.len())
+    }
 }
@@ -1,111 +1,206 @@
 use core::fmt;
-use std::{cmp, ops::Range};
+use std::{
+    cmp,
+    io::{Read, Seek, SeekFrom},
+    ops::Range,
+};
 
 use crate::BufGrowingExtIter;
 
+///
+/// A stream which can be read and seeked, to split it with `BufReadSplitter::new_seekable`
+pub trait ReadSeek: Read + Seek {}
+impl<T: Read + Seek + ?Sized> ReadSeek for T {}
+
+///
+/// The stream read by the extend buffer
+enum Source<'a> {
+    Read(&'a mut dyn Read),
+    Seek(&'a mut dyn ReadSeek),
+}
+impl<'a> Source<'a> {
+    ///
+    /// Read the stream, again if the read is interrupted
+    fn read_uninterrupted(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let res = match self {
+                Source::Read(reader) => reader.read(buf),
+                Source::Seek(reader) => reader.read(buf),
+            };
+            match res {
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                res => return res,
+            }
+        }
+    }
+}
+
+///
+/// Extend buffer, stored in a ring buffer so the bytes are never shifted when some are removed at the begin
+/// or pushed back at the begin
+///
+/// The ring is always initialised, the stream is read directly in its free space without zero-filling it.
 pub struct BufExt<'a> {
-    reader: &'a mut dyn std::io::Read, // The stream to read
-    ext: Vec<u8>,                      // Bytes in memory
-    sz_read_ext: usize,                // Size of the grow for each read
-    eos_reached: bool,                 // Indicate that End of stream was reached
-    inner_start: usize, // Starting position to have a constant speed whatever the size of the buffer is
+    source: Source<'a>,     // The stream to read
+    ring: Vec<u8>,          // Bytes in memory, its length is the capacity of the ring
+    head: usize,            // Position in the ring of the first byte
+    len: usize,             // Number of bytes in the ring
+    sz_read_ext: usize,     // Size of the grow for the next read
+    sz_read_ext_min: usize, // Size of the grow, when it is not increased
+    sz_read_ext_max: usize, // Maximum size of the grow
+    eos_reached: bool,      // Indicate that End of stream was reached
+    sz_read_total: u64,     // Number of bytes read from the stream since the splitting began
 }
 impl<'a> BufExt<'a> {
     ///
     /// Create a new buffer extender
     pub fn new(
-        reader: &'a mut dyn std::io::Read,
+        reader: &'a mut dyn Read,
         initiale_capacity: usize,
         sz_read_ext: usize,
+        sz_read_ext_max: usize,
     ) -> Self {
-        Self {
-            reader,
-            ext: Vec::with_capacity(initiale_capacity),
+        Self::with_source(
+            Source::Read(reader),
+            vec![0u8; cmp::max(initiale_capacity, 1)],
             sz_read_ext,
-            eos_reached: false,
-            inner_start: 0,
+            sz_read_ext_max,
+        )
+    }
+    ///
+    /// Create a new buffer extender keeping its bytes in the memory of `ring` (its content is dropped)
+    pub fn with_ring(
+        reader: &'a mut dyn Read,
+        mut ring: Vec<u8>,
+        initiale_capacity: usize,
+        sz_read_ext: usize,
+        sz_read_ext_max: usize,
+    ) -> Self {
+        if ring.len() < initiale_capacity {
+            ring.resize(cmp::max(initiale_capacity, 1), 0);
         }
+        Self::with_source(Source::Read(reader), ring, sz_read_ext, sz_read_ext_max)
+    }
+    ///
+    /// Create a new buffer extender over a seekable stream
+    pub fn new_seekable(
+        reader: &'a mut dyn ReadSeek,
+        initiale_capacity: usize,
+        sz_read_ext: usize,
+        sz_read_ext_max: usize,
+    ) -> Self {
+        Self::with_source(
+            Source::Seek(reader),
+            vec![0u8; cmp::max(initiale_capacity, 1)],
+            sz_read_ext,
+            sz_read_ext_max,
+        )
+    }
+    ///
+    /// Go to the position `pos` of the stream (counted like `stream_pos`), the bytes in memory are dropped
+    pub fn seek(&mut self, pos: u64) -> std::io::Result<()> {
+        let Source::Seek(reader) = &mut self.source else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "The stream is not seekable",
+            ));
+        };
+        // The positions are counted from the position of the stream when the splitting began
+        let start = reader.stream_position()? - self.sz_read_total;
+        reader.seek(SeekFrom::Start(start + pos))?;
+        self.head = 0;
+        self.len = 0;
+        self.eos_reached = false;
+        self.sz_read_total = pos;
+        self.sz_read_ext = self.sz_read_ext_min;
+        Ok(())
     }
     ///
     /// Extend the internal buffer by reading the input buffer
     pub fn extend(&mut self) -> std::io::Result<usize> {
-        // We have to reduce the inner buffer to avoid a buffer that can grow infinitly
-        self.ext.drain(..self.inner_start);
-        self.inner_start = 0;
+        let (sz_read, sz_asked) = self.read_in_free_space(self.sz_read_ext)?;
 
-        // Extends if needed
-        if self.ext.capacity() < self.ext.len() + self.sz_read_ext {
-            self.ext.reserve(self.sz_read_ext);
-        }
-
-        let start = self.ext.len();
-
-        //TODO: Read from a buffer into a vector --> Optimizable?
-        self.ext.resize(start + self.sz_read_ext, 0);
-        let sz_read = self.reader.read(&mut self.ext[start..])?;
-        if start + sz_read < self.ext.len() {
-            // Not all the buffer has been filling, so resize
-            self.ext.resize(start + sz_read, 0);
-        }
-
-        if sz_read == 0 {
-            self.eos_reached = true;
+        // Read more the next time if all the bytes asked are given
+        if sz_read == sz_asked && self.sz_read_ext < self.sz_read_ext_max {
+            self.sz_read_ext = cmp::min(self.sz_read_ext * 2, self.sz_read_ext_max);
         }
 
         // Return the position of the readed part
         Ok(sz_read)
     }
     ///
+    /// Extend the internal buffer by reading up to `sz` bytes of the input buffer
+    pub fn extend_by(&mut self, sz: usize) -> std::io::Result<usize> {
+        let (sz_read, _) = self.read_in_free_space(sz)?;
+        Ok(sz_read)
+    }
+    ///
     /// Unstack the buffer extender
     pub fn pop_buf_into(&mut self, buf: &mut [u8]) -> usize {
-        let sz = cmp::min(self.len(), buf.len());
-        buf[..sz].copy_from_slice(&self.ext[self.inner_start..self.inner_start + sz]);
-        self.inner_start += sz;
+        let sz = cmp::min(self.len, buf.len());
+        let (first, second) = self.slices(sz);
+        buf[..first.len()].copy_from_slice(first);
+        buf[first.len()..sz].copy_from_slice(second);
+        self.advance(sz);
         sz
     }
     ///
+    /// Remove the `sz` first bytes of the extend buffer
+    pub fn discard(&mut self, sz: usize) {
+        self.advance(sz);
+    }
+    ///
     /// Remove a certain number of elements at the begin of the extend buffer
     pub fn drain(&mut self, range: Range<usize>) {
-        if range.start == 0 {
-            self.inner_start += range.end;
+        let sz = range.end - range.start;
+        if range.start < self.len - range.end {
+            // The bytes before the range are moved forward
+            self.move_bytes(sz..sz + range.start, 0);
+            self.advance(sz);
         } else {
-            let new_start = range.start + self.inner_start;
-            let new_end = range.end + self.inner_start;
-            self.ext.drain(new_start..new_end);
+            // The bytes after the range are moved backward
+            self.move_bytes(range.start..self.len - sz, range.end);
+            self.len -= sz;
         }
     }
     ///
     /// Read the input buffer
     pub fn read_direct(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        self.reader.read(buf)
+        let sz_read = self.source.read_uninterrupted(buf)?;
+        self.sz_read_total += sz_read as u64;
+        self.sz_read_ext = self.sz_read_ext_min; // The extending buffer is no more used
+        Ok(sz_read)
     }
     ///
-    ///
+    /// Push back some bytes at the begin of the extend buffer
     pub fn push_at_begin(&mut self, buf: &[u8]) {
-        self.ext.drain(..self.inner_start);
-        self.inner_start = 0;
-
-        if buf.len() <= self.inner_start {
-            self.inner_start -= buf.len();
-            self.ext[self.inner_start..self.inner_start + buf.len()].copy_from_slice(buf);
-        } else {
-            self.ext[0..self.inner_start].copy_from_slice(&buf[0..self.inner_start]);
-            self.ext.splice(
-                self.inner_start..self.inner_start,
-                buf[self.inner_start..].iter().copied(),
-            );
-            self.inner_start = 0;
-        }
+        self.reserve(buf.len());
+        let cap = self.ring.len();
+        self.head = (self.head + cap - buf.len()) % cap;
+        self.len += buf.len();
+        let first_len = cmp::min(buf.len(), cap - self.head);
+        self.ring[self.head..self.head + first_len].copy_from_slice(&buf[..first_len]);
+        self.ring[..buf.len() - first_len].copy_from_slice(&buf[first_len..]);
+    }
+    ///
+    /// Number of bytes the ring can take without growing
+    pub fn free_space(&self) -> usize {
+        self.ring.len() - self.len
     }
     ///
     /// Actual length of the internal buffer
     pub fn len(&self) -> usize {
-        self.ext.len() - self.inner_start
+        self.len
     }
     ///
     /// Get a value
     pub fn at(&self, pos: usize) -> u8 {
-        self.ext[pos + self.inner_start]
+        self.ring[self.index(pos)]
+    }
+    ///
+    /// Position in the stream of the first byte of the extend buffer
+    pub fn stream_pos(&self) -> u64 {
+        self.sz_read_total - self.len as u64
     }
     ///
     /// Indicate if End Of Stream is reached or not
@@ -113,13 +208,151 @@ impl<'a> BufExt<'a> {
         self.eos_reached
     }
     ///
-    /// To iterate
-    pub fn iter_growing<'b>(&'b mut self) -> BufGrowingExtIter<'b, 'a> {
-        BufGrowingExtIter::new(self)
+    /// To iterate, starting at the position `start`
+    pub fn iter_growing<'b>(&'b mut self, start: usize) -> BufGrowingExtIter<'b, 'a> {
+        BufGrowingExtIter::new(self, start)
+    }
+    ///
+    /// Bytes from the position `start` until `end`, or until the end of the ring if it's before
+    pub fn contiguous(&self, start: usize, end: usize) -> &[u8] {
+        let index = self.index(start);
+        let sz = cmp::min(end - start, self.ring.len() - index);
+        &self.ring[index..index + sz]
+    }
+    ///
+    /// The stream and the bytes read from it but not yet taken
+    pub fn into_parts(self) -> (&'a mut dyn Read, Vec<u8>) {
+        let buffered = self.cloned_internal_vec();
+        let reader: &'a mut dyn Read = match self.source {
+            Source::Read(reader) => reader,
+            Source::Seek(reader) => reader,
+        };
+        (reader, buffered)
+    }
+    ///
+    /// The memory of the ring, to reuse it with `with_ring`
+    pub fn into_ring(self) -> Vec<u8> {
+        self.ring
     }
-    #[allow(dead_code)]
     pub fn cloned_internal_vec(&self) -> Vec<u8> {
-        self.ext[self.inner_start..].to_vec()
+        let (first, second) = self.slices(self.len);
+        [first, second].concat()
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    fn with_source(
+        source: Source<'a>,
+        ring: Vec<u8>,
+        sz_read_ext: usize,
+        sz_read_ext_max: usize,
+    ) -> Self {
+        Self {
+            source,
+            ring,
+            head: 0,
+            len: 0,
+            sz_read_ext,
+            sz_read_ext_min: sz_read_ext,
+            sz_read_ext_max: cmp::max(sz_read_ext, sz_read_ext_max),
+            eos_reached: false,
+            sz_read_total: 0,
+        }
+    }
+
+    ///
+    /// Read up to `sz` bytes in the free space following the last byte, till the end of the ring or the first byte
+    /// Return the number of bytes read and the number of bytes asked.
+    fn read_in_free_space(&mut self, sz: usize) -> std::io::Result<(usize, usize)> {
+        self.reserve(sz);
+
+        let start = self.index(self.len);
+        let end = if start < self.head || self.len == self.ring.len() {
+            self.head
+        } else {
+            self.ring.len()
+        };
+        let end = cmp::min(end, start + sz);
+        let sz_read = self.source.read_uninterrupted(&mut self.ring[start..end])?;
+        self.len += sz_read;
+
+        if sz_read == 0 {
+            self.eos_reached = true;
+        }
+        self.sz_read_total += sz_read as u64;
+        Ok((sz_read, end - start))
+    }
+    ///
+    /// Position in the ring of a position in the extend buffer
+    fn index(&self, pos: usize) -> usize {
+        let index = self.head + pos;
+        if index >= self.ring.len() {
+            index - self.ring.len()
+        } else {
+            index
+        }
+    }
+    ///
+    /// The `sz` first bytes, in two slices since they can go over the end of the ring
+    fn slices(&self, sz: usize) -> (&[u8], &[u8]) {
+        let first_end = cmp::min(self.head + sz, self.ring.len());
+        let second_end = self.head + sz - first_end;
+        (&self.ring[self.head..first_end], &self.ring[..second_end])
+    }
+    ///
+    /// Copy the bytes from the position `from` to the positions `to`, the ranges can overlap
+    fn move_bytes(&mut self, to: Range<usize>, from: usize) {
+        let mut done = 0;
+        let count = to.end - to.start;
+        let forward = from < to.start; // The copy begins by the end when the bytes go forward
+        while done < count {
+            // Copy the longest segment which doesn't go over the end of the ring
+            let remaining = count - done;
+            let (to_pos, from_pos) = if forward {
+                (to.end - done, from + count - done)
+            } else {
+                (to.start + done, from + done)
+            };
+            let sz = if forward {
+                let to_index = self.index(to_pos - 1) + 1;
+                let from_index = self.index(from_pos - 1) + 1;
+                let sz = cmp::min(remaining, cmp::min(to_index, from_index));
+                self.ring
+                    .copy_within(from_index - sz..from_index, to_index - sz);
+                sz
+            } else {
+                let to_index = self.index(to_pos);
+                let from_index = self.index(from_pos);
+                let cap = self.ring.len();
+                let sz = cmp::min(remaining, cmp::min(cap - to_index, cap - from_index));
+                self.ring.copy_within(from_index..from_index + sz, to_index);
+                sz
+            };
+            done += sz;
+        }
+    }
+    ///
+    /// Remove the `sz` first bytes
+    fn advance(&mut self, sz: usize) {
+        self.len -= sz;
+        self.head = if self.len == 0 { 0 } else { self.index(sz) };
+    }
+    ///
+    /// Grow the ring if there's not enough free space for `additional` bytes
+    /// Only the new space is zero-filled, once, then it's reused by all the next reads.
+    fn reserve(&mut self, additional: usize) {
+        let old_capacity = self.ring.len();
+        if old_capacity - self.len >= additional {
+            return;
+        }
+        let capacity = cmp::max(old_capacity * 2, self.len + additional);
+        self.ring.resize(capacity, 0);
+
+        // The bytes which were going over the end of the ring are moved after its old end
+        let wrapped = (self.head + self.len).saturating_sub(old_capacity);
+        if wrapped > 0 {
+            self.ring.copy_within(..wrapped, old_capacity);
+        }
     }
 }
 
@@ -130,7 +363,7 @@ impl<'a> fmt::Debug for BufExt<'a> {
         write!(
             f,
             "buf_extend={:?} sz_read_ext=[{:?}]",
-            String::from_utf8_lossy(self.ext.as_slice()),
+            String::from_utf8_lossy(&self.cloned_internal_vec()),
             self.sz_read_ext
         )
     }
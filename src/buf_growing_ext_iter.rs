@@ -5,12 +5,17 @@ pub struct BufGrowingExtIter<'a, 'b> {
     next_pos: usize,
 }
 impl<'a, 'b> BufGrowingExtIter<'a, 'b> {
-    pub fn new(buf_ext: &'a mut BufExt<'b>) -> Self {
+    pub fn new(buf_ext: &'a mut BufExt<'b>, start: usize) -> Self {
         Self {
             buf_ext,
-            next_pos: 0,
+            next_pos: start,
         }
     }
+    ///
+    /// Actual length of the extend buffer
+    pub fn len(&self) -> usize {
+        self.buf_ext.len()
+    }
 }
 impl<'a, 'b> Iterator for BufGrowingExtIter<'a, 'b> {
     type Item = std::io::Result<u8>;
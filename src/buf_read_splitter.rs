@@ -1,12 +1,23 @@
 use core::fmt;
-use std::{cmp, io::Read};
+use std::{
+    cmp,
+    io::{Chain, Cursor, IoSliceMut, Read},
+};
 
 use crate::errors::*;
 use crate::BufExt;
 use crate::MatchResult;
 use crate::Matcher;
 use crate::Options;
+use crate::PartInfo;
 use crate::PosSizeHelper;
+use crate::ReadSeek;
+use crate::SparseIndex;
+use crate::Statistics;
+
+///
+/// Function giving the matcher of each new part
+type MatcherFn<'a, T> = Box<dyn FnMut(usize, &PartInfo) -> T + 'a>;
 
 ///
 /// BufReadSplitter : See unit test or lib documentations for an example
@@ -19,6 +30,23 @@ pub struct BufReadSplitter<'a, T: Matcher> {
     first_read: bool,
     curr_limit_read: Option<usize>, // Counter for the size limit to read
     remain: usize,
+    ext_scanned: usize, // Bytes at the begin of the extend buffer already given to the matcher
+    ext_matched: usize, // Last bytes of the scanned ones in a match in progress, stopped by an error of the stream
+    part_offset: u64,   // Position in the stream of the current part
+    exact_remain: Option<u64>, // Bytes remaining in the current part when its size is fixed
+    exact_required: bool, // A part shorter than its size is an error
+    statistics: Statistics,
+    part_index: usize, // Index of the current part, the empty parts skipped are not counted
+    empty_skipped: bool, // The previous part was skipped because empty, the next one takes its index
+    matcher_fn: Option<MatcherFn<'a, T>>, // Give the matcher of each new part
+    first_matcher: Option<T>, // The matcher given to `new`, kept to seek the first part again
+    parts_gone: u64, // Parts gone forward by `skip_parts` or `count_remaining_parts` before an error of the stream
+    trim_leading: bool, // The begin of the part is still trimmed
+    trim_held: Vec<u8>, // Bytes which can be at the end of the part, held until the next content
+    trim_out: Vec<u8>, // Bytes to return before reading again
+    peeked: Vec<u8>, // Bytes of the last peek
+    empty_check_pending: bool, // The check of an empty part was stopped by an error of the stream
+    sparse_index: Option<SparseIndex>, // Position of some parts, to seek them
     #[cfg(feature = "log")]
     log_call_read: usize,
     #[cfg(feature = "log")]
@@ -30,28 +58,48 @@ pub struct BufReadSplitter<'a, T: Matcher> {
 /// Implementation
 impl<'a, T: Matcher> BufReadSplitter<'a, T> {
     pub fn new(reader: &'a mut dyn std::io::Read, matcher: T, options: Options) -> Self {
-        let max_read = options.limit_read;
-        Self {
-            //reader,
-            matcher,
-            buf_extend: BufExt::new(reader, options.initiale_sz_to_match, options.chunk_sz),
-            options,
-            matched: false,
-            first_read: true,
-            curr_limit_read: max_read,
-            remain: 0,
-            #[cfg(feature = "log")]
-            log_call_read: 0,
-            #[cfg(feature = "log")]
-            log_read_extend: 0,
-            #[cfg(feature = "log")]
-            log_resize_extend: 0,
-        }
+        let buf_extend = BufExt::new(
+            reader,
+            options.initiale_sz_to_match,
+            options.chunk_sz,
+            options.chunk_max_sz.unwrap_or(options.chunk_sz),
+        );
+        Self::with_buf_extend(buf_extend, matcher, options)
+    }
+    ///
+    /// Splitter over a seekable stream, to go to any part with `seek_to_part`
+    pub fn new_seekable(reader: &'a mut dyn ReadSeek, matcher: T, options: Options) -> Self {
+        let buf_extend = BufExt::new_seekable(
+            reader,
+            options.initiale_sz_to_match,
+            options.chunk_sz,
+            options.chunk_max_sz.unwrap_or(options.chunk_sz),
+        );
+        Self::with_buf_extend(buf_extend, matcher, options)
     }
     ///
     /// Change the matcher
     pub fn matcher(&mut self, matcher: T) {
-        self.matcher = matcher
+        self.matcher = matcher;
+        self.ext_scanned = 0; // The new matcher has seen nothing
+        self.ext_matched = 0;
+    }
+    ///
+    /// The matcher of the current part, to check its state once the part is read
+    pub fn get_matcher(&self) -> &T {
+        &self.matcher
+    }
+    ///
+    /// Set a function giving the matcher of each new part, called when the next part begins with its index
+    /// and the informations of the previous part (even for the empty parts skipped)
+    /// The parts are counted like `next` returns them, so the part after an empty part skipped gets the same index.
+    /// The matcher given to `new` is used for the first part, also when it's seeked again with `seek_to_part`.
+    /// After a seek, the previous part is given with a zero length.
+    pub fn set_matcher_fn<F>(&mut self, matcher_fn: F)
+    where
+        F: FnMut(usize, &PartInfo) -> T + 'a,
+    {
+        self.matcher_fn = Some(Box::new(matcher_fn));
     }
     ///
     /// Set a limit of bytes to read of a buffer part
@@ -60,34 +108,213 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
         self.curr_limit_read = opt_sz;
     }
     ///
+    /// Position in the stream of the first byte of the current part
+    pub fn part_offset(&self) -> u64 {
+        self.part_offset
+    }
+    ///
+    /// Statistics of the reading
+    pub fn statistics(&self) -> Statistics {
+        self.statistics
+    }
+    ///
     /// next buffer part
     pub fn next_part(&mut self) -> Result<Option<()>> {
         // We choose to return a Result<Option<()>> to be  representative of this logic :
         //   - call a function --> You have to manage a possible error
         //   - ok there's no error --> So is there something next
-        if self.matched == false {
-            self.skip_part()?;
+        if self.empty_check_pending && !self.skip_if_empty()? {
+            return Ok(Some(())); // The part started by the previous call is not empty
         }
-
-        if self.matched == false {
-            Ok(None) // At the end of the input buffer
-        } else {
-            #[cfg(feature = "log")]
-            log::debug!("Set matched to FALSE");
-
-            self.matched = false; // We are now at the next buffer, nothing even read, nothing even matched
-            self.curr_limit_read = self.options.limit_read;
-            Ok(Some(())) // It had just been stopping because it reached the separator
+        while self.start_next_part()? {
+            if !self.skip_if_empty()? {
+                return Ok(Some(())); // It had just been stopping because it reached the separator
+            }
+        }
+        Ok(None)
+    }
+    ///
+    /// The `n` first bytes not yet read of the current part, or less if the part ends before
+    /// These bytes are still returned by the next reads.
+    pub fn peek(&mut self, n: usize) -> Result<&[u8]> {
+        let n = cmp::min(n, self.curr_limit_read.unwrap_or(usize::MAX));
+        let mut peeked = std::mem::take(&mut self.peeked);
+        peeked.resize(n, 0);
+        let mut sz = 0;
+        while sz < n {
+            match self.part_read(&mut peeked[sz..]) {
+                Ok(0) => break,
+                Ok(sz_read) => sz += sz_read,
+                Err(err) => {
+                    self.unread(&peeked[..sz]);
+                    return Err(err.into());
+                }
+            }
+        }
+        peeked.truncate(sz);
+        self.unread(&peeked);
+        self.peeked = peeked;
+        Ok(&self.peeked)
+    }
+    ///
+    /// Next buffer part, which is exactly `sz` bytes whatever the matcher finds in it
+    /// The bytes after it are split with the matcher again (or with the fixed size of the options).
+    /// Reading the part returns an `UnexpectedEof` error if the stream ends before its size.
+    pub fn next_part_exact(&mut self, sz: u64) -> Result<Option<()>> {
+        if !self.start_next_part()? {
+            return Ok(None);
+        }
+        self.exact_remain = Some(sz);
+        self.exact_required = true;
+        Ok(Some(()))
+    }
+    ///
+    /// Splitter over the current part, to split it again with another matcher
+    /// Once the sub splitter is dropped, `next` or `next_part` goes to the next part, whatever was read of
+    /// the current one.
+    pub fn sub_splitter<'s, U: Matcher>(&'s mut self, matcher: U) -> BufReadSplitter<'s, U> {
+        self.sub_splitter_with_ring(matcher, Vec::new())
+    }
+    ///
+    /// Same as `sub_splitter`, but the buffer is kept in the memory of `ring` (see `into_ring`)
+    pub(crate) fn sub_splitter_with_ring<'s, U: Matcher>(
+        &'s mut self,
+        matcher: U,
+        ring: Vec<u8>,
+    ) -> BufReadSplitter<'s, U> {
+        let options = Options::default()
+            .set_reserve_sz_to_match(self.options.initiale_sz_to_match)
+            .set_extend_buffer_additionnal_sz(self.options.chunk_sz)
+            .clone();
+        let buf_extend = BufExt::with_ring(
+            self,
+            ring,
+            options.initiale_sz_to_match,
+            options.chunk_sz,
+            options.chunk_sz,
+        );
+        BufReadSplitter::with_buf_extend(buf_extend, matcher, options)
+    }
+    ///
+    /// Stop splitting, and give back the memory of the buffer to reuse it, the bytes in it are dropped
+    pub(crate) fn into_ring(self) -> Vec<u8> {
+        self.buf_extend.into_ring()
+    }
+    ///
+    /// Indicate that the limit of read of the current part is reached
+    pub(crate) fn limit_reached(&self) -> bool {
+        self.curr_limit_read == Some(0)
+    }
+    ///
+    /// Stop splitting, and give back the stream with the bytes already read from it but not yet returned
+    /// These bytes are the rest of the current part followed by the next ones, the separator ending the
+    /// current part is not in them if it is already matched.
+    pub fn into_parts(self) -> (&'a mut dyn std::io::Read, Vec<u8>) {
+        let (reader, buffered) = self.buf_extend.into_parts();
+        let mut remaining = self.trim_out;
+        remaining.extend_from_slice(&self.trim_held);
+        remaining.extend_from_slice(&buffered);
+        (reader, remaining)
+    }
+    ///
+    /// Stop splitting, and give back a reader of the rest of the stream, beginning with the bytes already
+    /// read from it but not yet returned (see `into_parts`)
+    pub fn into_remaining_reader(self) -> Chain<Cursor<Vec<u8>>, &'a mut dyn std::io::Read> {
+        let (reader, remaining) = self.into_parts();
+        Cursor::new(remaining).chain(reader)
+    }
+    ///
+    /// Go forward of `n` parts, like calling `next` `n` times without reading the parts
+    /// Return the number of parts gone forward, less than `n` if the end of the stream is reached.
+    /// The stream is read by the largest extension of the options (see `Options::set_adaptive_extend_buffer_max_sz`).
+    /// After an error of the stream (like `WouldBlock`), calling it again with the same `n` goes on with the
+    /// parts remaining, the parts already gone forward are counted in the result.
+    pub fn skip_parts(&mut self, n: usize) -> Result<usize> {
+        let mut sz_skipped = std::mem::take(&mut self.parts_gone) as usize;
+        while sz_skipped < n {
+            match self.next() {
+                Ok(true) => sz_skipped += 1,
+                Ok(false) => break,
+                Err(err) => {
+                    self.parts_gone = sz_skipped as u64;
+                    return Err(err);
+                }
+            }
+        }
+        Ok(sz_skipped)
+    }
+    ///
+    /// Count the parts after the current one (all the parts if `next` is not called yet), by reading
+    /// the stream until its end without copying the parts
+    /// After an error of the stream (like `WouldBlock`), calling it again goes on counting.
+    pub fn count_remaining_parts(&mut self) -> Result<u64> {
+        let mut count = std::mem::take(&mut self.parts_gone);
+        loop {
+            match self.next() {
+                Ok(true) => count += 1,
+                Ok(false) => return Ok(count),
+                Err(err) => {
+                    self.parts_gone = count;
+                    return Err(err);
+                }
+            }
+        }
+    }
+    ///
+    /// Keep the position of one part every `index.interval()` parts while reading, to go back to them with
+    /// `seek_to_part`
+    /// The index can be a saved one (see `SparseIndex::save`) of the same stream split the same way.
+    pub fn set_sparse_index(&mut self, index: SparseIndex) {
+        self.sparse_index = Some(index);
+        if !self.first_read {
+            self.index_part();
         }
     }
     ///
+    /// The sparse index, if it's set
+    pub fn sparse_index(&self) -> Option<&SparseIndex> {
+        self.sparse_index.as_ref()
+    }
+    ///
+    /// Go to the begin of the part `n` (counted from 0 like `next` returns them, the empty parts skipped
+    /// are not counted) as if `next` was called until it, return false if the stream ends before
+    /// The stream is seeked to the closest part before in the sparse index (or to the begin of the splitting),
+    /// unless the part `n` is after the current part and this one is after the closest part.
+    /// The splitter must be created with `new_seekable`.
+    pub fn seek_to_part(&mut self, n: usize) -> Result<bool> {
+        let (from, offset) = self
+            .sparse_index
+            .as_ref()
+            .and_then(|index| index.closest(n))
+            .unwrap_or((0, 0));
+        let to_skip = if !self.first_read && n > self.part_index && self.part_index >= from {
+            n - self.part_index
+        } else {
+            self.buf_extend.seek(offset)?;
+            self.restart_at(from, offset);
+            // `next` goes to the part `from`
+            n - from + 1
+        };
+        self.parts_gone = 0; // After an error of the stream, the parts to skip are counted again from here
+        Ok(self.skip_parts(to_skip)? == to_skip)
+    }
+    ///
     /// To manage the first call before the buffer is reading
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<bool> {
         if self.first_read {
-            self.first_read = false;
             // Manage case where the buffer is empty :
             let sz = self.buf_extend.extend()?;
-            Ok(sz > 0)
+            self.first_read = false;
+            if sz > 0 {
+                self.index_part();
+            }
+            if sz > 0 && self.skip_if_empty()? {
+                let opt = self.next_part()?;
+                Ok(opt.is_some())
+            } else {
+                Ok(sz > 0)
+            }
         } else {
             let opt = self.next_part()?;
             Ok(opt.is_some())
@@ -97,29 +324,310 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
     // ====== PRIVATE FUNCTIONS ====== //
 
     ///
-    /// Skip until the end of the part
+    /// Common part of the constructors
+    fn with_buf_extend(buf_extend: BufExt<'a>, matcher: T, options: Options) -> Self {
+        let max_read = options.limit_read;
+        let fixed_size = options.fixed_size;
+        Self {
+            matcher,
+            buf_extend,
+            options,
+            matched: false,
+            first_read: true,
+            curr_limit_read: max_read,
+            remain: 0,
+            ext_scanned: 0,
+            ext_matched: 0,
+            part_offset: 0,
+            exact_remain: fixed_size.map(|sz| sz as u64),
+            exact_required: false,
+            statistics: Statistics::default(),
+            part_index: 0,
+            empty_skipped: false,
+            matcher_fn: None,
+            first_matcher: None,
+            parts_gone: 0,
+            trim_leading: true,
+            trim_held: Vec::new(),
+            trim_out: Vec::new(),
+            peeked: Vec::new(),
+            empty_check_pending: false,
+            sparse_index: None,
+            #[cfg(feature = "log")]
+            log_call_read: 0,
+            #[cfg(feature = "log")]
+            log_read_extend: 0,
+            #[cfg(feature = "log")]
+            log_resize_extend: 0,
+        }
+    }
+    ///
+    /// Keep the position of the current part in the sparse index
+    fn index_part(&mut self) {
+        if let Some(index) = self.sparse_index.as_mut() {
+            index.record(self.part_index, self.part_offset);
+        }
+    }
+    ///
+    /// Set the state before the part `index`, beginning at `offset` in the stream just seeked
+    fn restart_at(&mut self, index: usize, offset: u64) {
+        if index == 0 {
+            if let Some(matcher) = self.first_matcher.take() {
+                self.matcher = matcher;
+            }
+        }
+        self.matcher.reset(index == 0);
+        if index == 0 {
+            // As if nothing was read
+            self.first_read = true;
+            self.matched = false;
+            self.part_index = 0;
+        } else {
+            // As if the separator of the previous part was just matched
+            self.first_read = false;
+            self.matched = true;
+            self.part_index = index - 1;
+        }
+        self.part_offset = offset;
+        self.remain = 0;
+        self.ext_scanned = 0;
+        self.ext_matched = 0;
+        self.curr_limit_read = self.options.limit_read;
+        self.exact_remain = self.options.fixed_size.map(|sz| sz as u64);
+        self.exact_required = false;
+        self.trim_leading = true;
+        self.trim_held.clear();
+        self.trim_out.clear();
+        self.empty_check_pending = false;
+        self.empty_skipped = false;
+        self.parts_gone = 0;
+    }
+    ///
+    /// Size of the reads when a part is skipped : the largest extension of the options, or the free space of
+    /// the extend buffer if it's larger, so the extend buffer doesn't grow beyond the sizes chosen
+    fn skip_read_sz(&self) -> usize {
+        let sz_ext_max = self.options.chunk_max_sz.unwrap_or(self.options.chunk_sz);
+        cmp::max(sz_ext_max, self.buf_extend.free_space())
+    }
+    ///
+    /// Skip until the end of the part, the bytes are removed from the extend buffer without being copied
     fn skip_part(&mut self) -> Result<()> {
         #[cfg(feature = "log")]
         log::debug!("====next_part skip this :");
 
-        // Have to read until end of buffer or separator
-        let mut buf = [0u8; 100];
-        while {
-            let sz_read = match self.internal_read(&mut buf) {
-                Ok(o) => o,
-                Err(err) => return Err(err.into()).into(),
-            };
-            // while condition :
-            // At the end if :
-            //   - matched and there's nothing more to take in the extend buffer
-            //   - or end of file
-            (self.matched == false || self.remain > 0) && sz_read != 0
-        } {}
+        if self.matched {
+            // Only the rest of the part before the separator
+            self.buf_extend.discard(self.remain);
+            self.remain = 0;
+        }
+        while !self.matched {
+            if let Some(remain) = self.exact_remain {
+                if self.buf_extend.len() == 0
+                    && self.buf_extend.extend_by(self.skip_read_sz())? == 0
+                {
+                    // The stream ends before the end of the part
+                    self.read_exact_part(&mut [0u8; 1], remain)?;
+                    break;
+                }
+                let sz = cmp::min(remain, self.buf_extend.len() as u64);
+                self.buf_extend.discard(sz as usize);
+                self.exact_remain = Some(remain - sz);
+                self.matched = remain == sz;
+            } else if self.ext_scanned < self.buf_extend.len() || self.ext_matched > 0 {
+                let (state, sz_matched, pos) = self.search_match_in_buf_extend(usize::MAX)?;
+                if let MatchResult::Match(take_left, take_right) = state {
+                    let ps = PosSizeHelper::from_match(take_left, take_right, sz_matched, pos);
+                    self.buf_extend.discard(ps.next_content_pos());
+                    self.matched = true;
+                    self.ext_scanned = 0;
+                }
+            } else {
+                // All the bytes have been given to the matcher, the next ones are read in place of them
+                self.buf_extend.discard(self.ext_scanned);
+                self.ext_scanned = 0;
+                if self.buf_extend.extend_by(self.skip_read_sz())? == 0 {
+                    break; // End of the stream
+                }
+            }
+        }
         #[cfg(feature = "log")]
         log::debug!("====next_part skip end====");
         Ok(())
     }
     ///
+    /// Go to the begin of the next part, return false at the end of the input buffer
+    fn start_next_part(&mut self) -> Result<bool> {
+        self.skip_part()?;
+
+        // At the end of the input buffer, or a part of fixed size ended at the end of the input buffer
+        if !self.matched || (self.exact_remain.is_some() && !self.has_data()?) {
+            Ok(false)
+        } else {
+            #[cfg(feature = "log")]
+            log::debug!("Set matched to FALSE");
+
+            self.matched = false; // We are now at the next buffer, nothing even read, nothing even matched
+            self.curr_limit_read = self.options.limit_read;
+            let prev_part = PartInfo {
+                offset: self.part_offset,
+                len: self.buf_extend.stream_pos() - self.part_offset,
+            };
+            self.part_offset = self.buf_extend.stream_pos();
+            if self.empty_skipped {
+                self.empty_skipped = false; // The empty part skipped had the same index
+            } else {
+                self.part_index += 1;
+            }
+            self.index_part();
+            if let Some(matcher_fn) = self.matcher_fn.as_mut() {
+                let matcher = matcher_fn(self.part_index, &prev_part);
+                let prev_matcher = std::mem::replace(&mut self.matcher, matcher);
+                if self.first_matcher.is_none() {
+                    self.first_matcher = Some(prev_matcher); // The first matcher replaced is the one of `new`
+                }
+                self.ext_scanned = 0; // The new matcher has seen nothing
+                self.ext_matched = 0;
+            }
+            self.matcher.part_start();
+            self.exact_remain = self.options.fixed_size.map(|sz| sz as u64);
+            self.exact_required = false;
+            self.trim_leading = true;
+            self.trim_held.clear();
+            self.trim_out.clear();
+            Ok(true)
+        }
+    }
+    ///
+    /// Skip the part just started if it's empty and the option asks it
+    fn skip_if_empty(&mut self) -> std::io::Result<bool> {
+        // A fixed size part is never empty
+        if !self.options.skip_empty_parts || self.exact_remain.is_some() {
+            return Ok(false);
+        }
+        self.empty_check_pending = true; // Still set if the stream returns an error
+        let mut byte = [0u8; 1];
+        let sz_read = self.part_read(&mut byte)?;
+        self.empty_check_pending = false;
+        if sz_read > 0 {
+            self.unread(&byte); // The byte read is given back
+            return Ok(false);
+        }
+        self.statistics.skipped_empty_parts += 1;
+        self.empty_skipped = true;
+        Ok(true)
+    }
+    ///
+    /// Give back bytes read from the current part, so they are returned again by the next read
+    fn unread(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        if self.options.trim.is_some() {
+            self.trim_out.splice(0..0, bytes.iter().copied());
+            return;
+        }
+        self.buf_extend.push_at_begin(bytes);
+        if let Some(remain) = self.exact_remain {
+            // The end of a part of known size is reached only by reading it
+            self.exact_remain = Some(remain + bytes.len() as u64);
+            self.matched = false;
+        } else if self.matched {
+            self.remain += bytes.len();
+        } else {
+            self.ext_scanned += bytes.len(); // They have already been given to the matcher
+        }
+    }
+    ///
+    /// Read the part without the bytes to trim at its begin and its end
+    fn trimmed_read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if !self.trim_out.is_empty() {
+                let sz = cmp::min(self.trim_out.len(), buf.len());
+                buf[..sz].copy_from_slice(&self.trim_out[..sz]);
+                self.trim_out.drain(..sz);
+                return Ok(sz);
+            }
+
+            let sz_read = self.internal_read(buf)?;
+            if sz_read == 0 {
+                // End of the part, the held bytes are at its end
+                self.trim_held.clear();
+                return Ok(0);
+            }
+
+            let to_trim = self.options.trim.as_deref().unwrap_or_default();
+            let start = if self.trim_leading {
+                buf[..sz_read]
+                    .iter()
+                    .position(|b| !to_trim.contains(b))
+                    .unwrap_or(sz_read)
+            } else {
+                0
+            };
+            let opt_last = buf[start..sz_read]
+                .iter()
+                .rposition(|b| !to_trim.contains(b));
+
+            match opt_last {
+                None if self.trim_leading => {} // Still at the begin of the part
+                None => self.trim_held.extend_from_slice(&buf[start..sz_read]),
+                Some(last) => {
+                    self.trim_leading = false;
+                    let end = start + last + 1;
+                    if self.trim_held.is_empty() {
+                        buf.copy_within(start..end, 0);
+                        self.trim_held.extend_from_slice(&buf[end..sz_read]);
+                        return Ok(end - start);
+                    }
+                    // The held bytes are followed by a content, so they are returned first
+                    self.trim_out = std::mem::take(&mut self.trim_held);
+                    self.trim_out.extend_from_slice(&buf[start..end]);
+                    self.trim_held.extend_from_slice(&buf[end..sz_read]);
+                }
+            }
+        }
+    }
+    ///
+    /// Indicate if there's still something to read
+    fn has_data(&mut self) -> std::io::Result<bool> {
+        Ok(self.buf_extend.len() > 0 || self.buf_extend.extend()? > 0)
+    }
+    ///
+    /// Read a part whose size is known, without using the matcher
+    fn read_exact_part(&mut self, buf: &mut [u8], remain: u64) -> std::io::Result<usize> {
+        let sz_max = cmp::min(remain, buf.len() as u64) as usize;
+        let mut sz_read = self.buf_extend.pop_buf_into(&mut buf[..sz_max]);
+        if sz_read < sz_max {
+            sz_read += self.read_direct_after(buf, sz_read, sz_max)?;
+        }
+        let remain = remain - sz_read as u64;
+        self.exact_remain = Some(remain);
+
+        if remain == 0 {
+            // The end of the part is reached as if a separator was matched
+            self.matched = true;
+            self.remain = 0;
+        } else if sz_read == 0
+            && sz_max > 0
+            && (self.exact_required || !self.options.fixed_size_partial)
+        {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "The last part is shorter than its size",
+            ));
+        }
+        Ok(sz_read)
+    }
+    ///
+    /// Read the part, trimmed or not
+    fn part_read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.options.trim.is_some() {
+            self.trimmed_read(buf)
+        } else {
+            self.internal_read(buf)
+        }
+    }
+    ///
     /// Common read buffer function
     fn internal_read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         #[cfg(feature = "log")]
@@ -149,6 +657,8 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
                 );
                 Ok(sz)
             }
+        } else if let Some(remain) = self.exact_remain {
+            self.read_exact_part(buf, remain)
         } else {
             // Initialize the size to return
             let mut sz_read = 0;
@@ -160,30 +670,45 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
                 let (state, sz_matched, pos) = self.search_match_in_buf_extend(buf.len())?;
                 if let MatchResult::Match(take_left, take_right) = state {
                     let ps = PosSizeHelper::from_match(take_left, take_right, sz_matched, pos);
-                    sz_read = self.buf_extend.pop_buf_into(&mut buf[..ps.skipped_pos()]);
-                    self.buf_extend.drain(0..ps.skipped_len());
-                    // The next read have to stop the read (return sz_read=0)
+                    self.buf_extend
+                        .drain(ps.skipped_pos()..ps.next_content_pos());
+                    let sz_max = cmp::min(ps.skipped_pos(), buf.len());
+                    sz_read = self.buf_extend.pop_buf_into(&mut buf[..sz_max]);
+                    // The next read have to stop the read (return sz_read=0) once the remain is returned
                     self.matched = true;
-                    self.remain = 0;
+                    self.remain = ps.skipped_pos() - sz_read;
+                    self.ext_scanned = 0;
+                    self.ext_matched = 0;
                     return Ok(sz_read);
                 } else {
-                    sz_read = self.buf_extend.pop_buf_into(buf);
+                    // Only the bytes already given to the matcher
+                    let sz_max = cmp::min(self.ext_scanned, buf.len());
+                    sz_read = self.buf_extend.pop_buf_into(&mut buf[..sz_max]);
+                    self.ext_scanned -= sz_read;
                 }
             }
+            // Bytes popped from the extend buffer don't have to be given to the matcher again
+            let sz_scanned = sz_read;
+
             // Feed the remaining part by consumming the input buffer
             //todo: is necessary if there's a match inside it ?
             if sz_read < buf.len() {
-                sz_read += self.buf_extend.read_direct(&mut buf[sz_read..])?;
+                sz_read += self.read_direct_after(buf, sz_read, buf.len())?;
             }
 
-            match self.search_match(buf, sz_read)? {
+            let opt_ps = match self.search_match(buf, sz_scanned, sz_read) {
+                Ok(opt_ps) => opt_ps,
+                Err(err) => return self.suspend_match(buf, sz_read, err),
+            };
+            match opt_ps {
                 Some(ps_absolute) => {
-                    let ps_bufext = PosSizeHelper::from_relative(&ps_absolute, buf.len());
+                    // The extend buffer follows the bytes read, the stream can give less than the size of `buf`
+                    let ps_bufext = PosSizeHelper::from_relative(&ps_absolute, sz_read);
 
                     // Save the part next to the matched part if there's one
                     // (we have to push at the begin because the buffer can already contains datas)
                     //todo: is there a simpler way ?
-                    if ps_absolute.next_content_pos() < buf.len() {
+                    if ps_absolute.next_content_pos() < sz_read {
                         self.buf_extend
                             .push_at_begin(&buf[ps_absolute.next_content_pos()..sz_read]);
                     }
@@ -195,11 +720,11 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
                     }
 
                     // If there's something next to return in the buf_extend
-                    if ps_absolute.skipped_pos() > buf.len() {
+                    if ps_absolute.skipped_pos() > sz_read {
                         self.remain = ps_bufext.skipped_pos();
                     }
 
-                    let sz_to_return = cmp::min(buf.len(), ps_absolute.skipped_pos());
+                    let sz_to_return = cmp::min(sz_read, ps_absolute.skipped_pos());
 
                     // Debug
                     #[cfg(feature = "log")]
@@ -212,6 +737,8 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
                     );
 
                     self.matched = true;
+                    self.ext_scanned = 0;
+                    self.ext_matched = 0;
                     Ok(sz_to_return)
                 }
                 None => {
@@ -233,14 +760,15 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
     fn search_match(
         &mut self,
         buf: &[u8],
+        sz_scanned: usize,
         sz_read: usize,
     ) -> std::io::Result<Option<PosSizeHelper>> {
         // Initialize
         let mut sz_matched = 0usize; //Size matched
-        let mut pos = 0usize; //Absolute position of the last position that matched
+        let mut pos = sz_scanned; //Absolute position of the last position that matched
 
         // Search in the buffer
-        let mut state = self.search_match_in_buffer(buf, sz_read, &mut sz_matched, &mut pos);
+        let mut state = self.search_match_in_buffer(&buf[..sz_read], &mut sz_matched, &mut pos);
         if matches!(state, MatchResult::NeedNext) {
             // Search in the extended buffer
             state = self.continue_match_in_buf_extend(&mut sz_matched, &mut pos)?;
@@ -261,13 +789,19 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
     fn search_match_in_buffer(
         &mut self,
         buf: &[u8],
-        sz_read: usize,
         sz_matched: &mut usize,
         pos: &mut usize,
     ) -> MatchResult {
         let mut latest_state = MatchResult::Mismatch;
-        for el in buf[..sz_read].into_iter() {
-            latest_state = self.matcher.sequel(*el, *sz_matched);
+        while *pos < buf.len() {
+            if *sz_matched == 0 {
+                // Bytes that can't begin a match are skipped at once
+                *pos += self.matcher.skip(&buf[*pos..]);
+                if *pos == buf.len() {
+                    break;
+                }
+            }
+            latest_state = self.matcher.sequel(buf[*pos], *sz_matched);
             match latest_state {
                 MatchResult::NeedNext => *sz_matched += 1,
                 MatchResult::Match(_, _) => {
@@ -288,9 +822,20 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
     ) -> std::io::Result<MatchResult> {
         // We are here because the begin of the potentiel pattern has been found in the buffer part, so we have
         // to determine if it is really matched or not to stop the buffer.
-        let it = self.buf_extend.iter_growing();
+        let mut sz_fed = 0usize; // Bytes of the extend buffer given to the matcher
+        let it = self.buf_extend.iter_growing(0);
         for res in it {
-            let state = self.matcher.sequel(res?, *sz_matched);
+            let el = match res {
+                Ok(el) => el,
+                Err(err) => {
+                    // The match in progress is resumed at the next read
+                    self.ext_scanned = sz_fed;
+                    self.ext_matched = *sz_matched;
+                    return Err(err);
+                }
+            };
+            let state = self.matcher.sequel(el, *sz_matched);
+            sz_fed += 1;
             match state {
                 MatchResult::NeedNext => {
                     *sz_matched += 1;
@@ -299,60 +844,143 @@ impl<'a, T: Matcher> BufReadSplitter<'a, T> {
                     *sz_matched += 1;
                     return Ok(state);
                 }
-                MatchResult::Mismatch => return Ok(state),
+                MatchResult::Mismatch => {
+                    self.ext_scanned = sz_fed;
+                    return Ok(state);
+                }
             }
             *pos += 1;
         }
         // We are at the end of the stream => we manage the EOS call
-        if false == self.buf_extend.eos_reached() {
-            Ok(MatchResult::Mismatch)
+        let state = if !self.buf_extend.eos_reached() {
+            MatchResult::Mismatch
         } else {
-            let state = self.matcher.sequel_eos(*sz_matched - 1);
-            if matches!(state, MatchResult::Match(_, _)) {
-                *pos -= 1;
-            }
-            Ok(state)
+            self.matcher.sequel_eos(*sz_matched - 1)
+        };
+        if matches!(state, MatchResult::Match(_, _)) {
+            *pos -= 1;
+        } else {
+            self.ext_scanned = sz_fed;
         }
+        Ok(state)
     }
 
     fn search_match_in_buf_extend(
         &mut self,
         dest_len: usize,
     ) -> std::io::Result<(MatchResult, usize, usize)> {
-        let len_origine = self.buf_extend.len().clone();
-        let mut sz_matched = 0usize; //Size matched
-        let mut pos = 0usize; //Absolute position of the latest position that matched
+        let mut sz_matched = self.ext_matched; //Size matched, not 0 if a match in progress is resumed
+        let mut pos = self.ext_scanned; //Absolute position of the latest position that matched
+        self.ext_matched = 0;
 
-        let it = self.buf_extend.iter_growing();
-        for res in it {
-            let state = self.matcher.sequel(res?, sz_matched);
-            match state {
-                MatchResult::NeedNext => {
-                    sz_matched += 1;
+        'search: loop {
+            // The bytes already given to the matcher are not given twice, and the bytes that can't begin
+            // a match are skipped at once
+            if sz_matched == 0 {
+                let limit = cmp::min(self.buf_extend.len(), dest_len);
+                if pos < limit {
+                    pos = self.skip_in_buf_extend(pos, limit);
                 }
-                MatchResult::Match(_, _) => {
-                    sz_matched += 1;
-                    return Ok((state, sz_matched, pos));
+                if pos >= limit {
+                    self.ext_scanned = pos;
+                    return Ok((MatchResult::Mismatch, sz_matched, pos));
                 }
-                MatchResult::Mismatch => {
-                    sz_matched = 0;
-                    if pos + 1 == len_origine || pos + 1 == dest_len {
+            }
+
+            let mut it = self.buf_extend.iter_growing(pos);
+            while let Some(res) = it.next() {
+                let el = match res {
+                    Ok(el) => el,
+                    Err(err) => {
+                        // The match in progress is resumed at the next read
+                        self.ext_scanned = pos;
+                        self.ext_matched = sz_matched;
+                        return Err(err);
+                    }
+                };
+                let state = self.matcher.sequel(el, sz_matched);
+                match state {
+                    MatchResult::NeedNext => {
+                        sz_matched += 1;
+                    }
+                    MatchResult::Match(_, _) => {
+                        sz_matched += 1;
                         return Ok((state, sz_matched, pos));
                     }
+                    MatchResult::Mismatch => {
+                        sz_matched = 0;
+                        // Stop when the extend buffer or the destination is covered
+                        if pos + 1 >= cmp::min(it.len(), dest_len) {
+                            self.ext_scanned = pos + 1;
+                            return Ok((state, sz_matched, pos));
+                        }
+                        pos += 1;
+                        continue 'search;
+                    }
                 }
+                pos += 1;
             }
-            pos += 1;
+            break;
         }
         // We are at the end of the stream => we manage the EOS call
-        if false == self.buf_extend.eos_reached() {
-            Ok((MatchResult::Mismatch, sz_matched, pos))
+        let state = if !self.buf_extend.eos_reached() {
+            MatchResult::Mismatch
+        } else {
+            self.matcher.sequel_eos(sz_matched - 1)
+        };
+        if matches!(state, MatchResult::Match(_, _)) {
+            pos -= 1;
+        } else {
+            self.ext_scanned = pos;
+        }
+        Ok((state, sz_matched, pos))
+    }
+    ///
+    /// Read the stream directly in `buf`, after the `sz_read` bytes already placed and until `end`
+    /// If the stream returns an error, the bytes already placed are returned first.
+    fn read_direct_after(
+        &mut self,
+        buf: &mut [u8],
+        sz_read: usize,
+        end: usize,
+    ) -> std::io::Result<usize> {
+        match self.buf_extend.read_direct(&mut buf[sz_read..end]) {
+            Err(_) if sz_read > 0 => Ok(0), // An error which persists comes again at the next read
+            res => res,
+        }
+    }
+    ///
+    /// Keep the match in progress when the stream returns an error, to resume it at the next read
+    /// The bytes of `buf` in this match go back to the extend buffer, the ones before it are returned.
+    fn suspend_match(
+        &mut self,
+        buf: &[u8],
+        sz_read: usize,
+        err: std::io::Error,
+    ) -> std::io::Result<usize> {
+        let sz_in_buf = self.ext_matched - self.ext_scanned;
+        let sz_content = sz_read - sz_in_buf;
+        self.buf_extend.push_at_begin(&buf[sz_content..sz_read]);
+        self.ext_scanned = self.ext_matched;
+        if sz_content > 0 {
+            Ok(sz_content)
         } else {
-            let state = self.matcher.sequel_eos(sz_matched - 1);
-            if matches!(state, MatchResult::Match(_, _)) {
-                pos -= 1;
+            Err(err)
+        }
+    }
+    ///
+    /// Skip the bytes of the extend buffer that can't begin a match, from `pos` until `limit`
+    fn skip_in_buf_extend(&mut self, pos: usize, limit: usize) -> usize {
+        let mut pos = pos;
+        while pos < limit {
+            let slice = self.buf_extend.contiguous(pos, limit);
+            let sz_skipped = self.matcher.skip(slice);
+            pos += sz_skipped;
+            if sz_skipped < slice.len() {
+                break;
             }
-            Ok((state, sz_matched, pos))
         }
+        pos
     }
 
     ///
@@ -383,7 +1011,7 @@ impl<'a, T: Matcher> Read for BufReadSplitter<'a, T> {
     ///
     /// Read until the begin of a match or end of the buffer
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        if self.matched == true && self.remain == 0 {
+        if self.matched && self.remain == 0 && self.trim_out.is_empty() {
             #[cfg(feature = "log")]
             log::debug!("Must call next first !");
 
@@ -398,13 +1026,37 @@ impl<'a, T: Matcher> Read for BufReadSplitter<'a, T> {
                 Ok(0)
             } else {
                 let buf_slice = &mut buf[..max];
-                let sz_read = self.internal_read(buf_slice)?;
+                let sz_read = self.part_read(buf_slice)?;
                 self.curr_limit_read = Some(sz - sz_read);
                 Ok(sz_read)
             }
         } else {
-            self.internal_read(buf)
+            self.part_read(buf)
+        }
+    }
+    ///
+    /// Fill the buffers one after the other, until the begin of a match or the end of the stream
+    /// A buffer is entirely filled before going to the next one, so the bytes are placed like
+    /// with a `read_exact` for each buffer.
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
+        let mut sz_total = 0;
+        for buf in bufs.iter_mut() {
+            let mut pos = 0;
+            while pos < buf.len() {
+                let sz_read = match self.read(&mut buf[pos..]) {
+                    Ok(sz_read) => sz_read,
+                    // The bytes already placed are returned, an error which persists comes again at the next read
+                    Err(_) if sz_total + pos > 0 => return Ok(sz_total + pos),
+                    Err(err) => return Err(err),
+                };
+                if sz_read == 0 {
+                    return Ok(sz_total + pos); // End of the part
+                }
+                pos += sz_read;
+            }
+            sz_total += pos;
         }
+        Ok(sz_total)
     }
 }
 ///
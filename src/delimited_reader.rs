@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+use std::io::Read;
+use std::ops::Range;
+
+use crate::BufReadSplitter;
+use crate::Matcher;
+use crate::ReaderOptions;
+use crate::RecordError;
+
+///
+/// Reader of delimited text, split in records with a matcher and each record in fields with another one
+/// (like `\n` and `\t`, or `<RS>` and `<FS>`), see `next_record` and `visit_record`
+///
+/// The empty records are skipped, the fields are returned as they are (nothing is unquoted).
+/// The limit of the options (see `ReaderOptions::set_limit_record`) caps the size of a record with the
+/// separators of its fields.
+pub struct DelimitedReader<'a, R: Matcher, F: Matcher + Clone> {
+    reader: BufReadSplitter<'a, R>,
+    field_matcher: F, // Cloned for each record
+    has_header: bool,
+    header: Option<Vec<Vec<u8>>>,
+    max_fields: Option<usize>,
+    index: usize,              // Index of the next record
+    record: Vec<u8>,           // Bytes of the fields of the current record
+    fields: Vec<Range<usize>>, // Position of each field in `record`
+    field_ring: Vec<u8>,       // Memory of the buffer splitting the fields, reused for each record
+}
+impl<'a, R: Matcher, F: Matcher + Clone> DelimitedReader<'a, R, F> {
+    pub fn new(
+        reader: &'a mut dyn std::io::Read,
+        record_matcher: R,
+        field_matcher: F,
+        options: ReaderOptions,
+    ) -> Self {
+        let mut record_options = options.splitter_options();
+        record_options
+            .set_skip_empty_parts(true)
+            .set_limit_read(options.limit_read());
+        Self {
+            reader: BufReadSplitter::new(reader, record_matcher, record_options),
+            field_matcher,
+            has_header: false,
+            header: None,
+            max_fields: None,
+            index: 0,
+            record: Vec::new(),
+            fields: Vec::new(),
+            field_ring: Vec::new(),
+        }
+    }
+    ///
+    /// The first record is a header row, it's not returned as a record (see `header`)
+    pub fn set_has_header(&mut self, has_header: bool) {
+        self.has_header = has_header;
+    }
+    ///
+    /// Set a maximum number of fields, a record with more fields is malformed
+    pub fn set_max_fields(&mut self, opt_max: Option<usize>) {
+        self.max_fields = opt_max;
+    }
+    ///
+    /// Fields of the header row, read if not done yet (None without header row or if the stream is empty)
+    pub fn header(&mut self) -> std::result::Result<Option<&[Vec<u8>]>, RecordError> {
+        self.read_header()?;
+        Ok(self.header.as_deref())
+    }
+    ///
+    /// Fields of the next record, None at the end of the stream
+    /// After a `Malformed` error, the next call goes on with the next record.
+    pub fn next_record(&mut self) -> std::result::Result<Option<Vec<Cow<'_, [u8]>>>, RecordError> {
+        if !self.read_record()? {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.fields
+                .iter()
+                .map(|range| Cow::Borrowed(&self.record[range.clone()]))
+                .collect(),
+        ))
+    }
+    ///
+    /// Give each field of the next record with its index to `visitor`, return false at the end of the stream
+    pub fn visit_record<V>(&mut self, mut visitor: V) -> std::result::Result<bool, RecordError>
+    where
+        V: FnMut(usize, &[u8]),
+    {
+        if !self.read_record()? {
+            return Ok(false);
+        }
+        for (i, range) in self.fields.iter().enumerate() {
+            visitor(i, &self.record[range.clone()]);
+        }
+        Ok(true)
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Read the header row if it's expected and not read yet
+    fn read_header(&mut self) -> std::result::Result<(), RecordError> {
+        if self.has_header && self.header.is_none() && self.read_fields()? {
+            self.header = Some(
+                self.fields
+                    .iter()
+                    .map(|range| self.record[range.clone()].to_vec())
+                    .collect(),
+            );
+        }
+        Ok(())
+    }
+    ///
+    /// Read the next record which is not the header row
+    fn read_record(&mut self) -> std::result::Result<bool, RecordError> {
+        self.read_header()?;
+        self.read_fields()
+    }
+    ///
+    /// Read the fields of the next record, return false at the end of the stream
+    fn read_fields(&mut self) -> std::result::Result<bool, RecordError> {
+        if !self.reader.next().map_err(RecordError::Read)? {
+            return Ok(false);
+        }
+        let index = self.index;
+        let offset = self.reader.part_offset();
+        self.index += 1;
+
+        self.record.clear();
+        self.fields.clear();
+        let ring = std::mem::take(&mut self.field_ring);
+        let mut splitter = self
+            .reader
+            .sub_splitter_with_ring(self.field_matcher.clone(), ring);
+        let res = Self::split_fields(
+            &mut splitter,
+            &mut self.record,
+            &mut self.fields,
+            self.max_fields,
+        );
+        self.field_ring = splitter.into_ring();
+        let all_fields = res?;
+        if self.reader.limit_reached() {
+            return Err(RecordError::Malformed {
+                index,
+                offset,
+                reason: "record too large",
+            });
+        }
+        if !all_fields {
+            return Err(RecordError::Malformed {
+                index,
+                offset,
+                reason: "too many fields",
+            });
+        }
+        Ok(true)
+    }
+    ///
+    /// Read each field of the record in `record`, with its position in `fields`
+    /// Return false if there are more fields than `max_fields`.
+    fn split_fields(
+        splitter: &mut BufReadSplitter<'_, F>,
+        record: &mut Vec<u8>,
+        fields: &mut Vec<Range<usize>>,
+        max_fields: Option<usize>,
+    ) -> std::result::Result<bool, RecordError> {
+        while splitter.next().map_err(RecordError::Read)? {
+            if Some(fields.len()) == max_fields {
+                return Ok(false);
+            }
+            let start = record.len();
+            splitter
+                .read_to_end(record)
+                .map_err(|err| RecordError::Read(err.into()))?;
+            fields.push(start..record.len());
+        }
+        Ok(true)
+    }
+}
@@ -10,3 +10,36 @@ pub enum BufReadSplitterError {
 pub type Result<T> = std::result::Result<T, BufReadSplitterError>;
 */
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+use thiserror::Error;
+
+///
+/// Error returned while iterating over the records of a stream
+#[derive(Error, Debug)]
+pub enum RecordError {
+    ///
+    /// The stream can't be read, the iteration can't go further
+    #[error("Read error: `{0}`")]
+    Read(Box<dyn std::error::Error>),
+    ///
+    /// The record is incomplete, the iteration can go on with the next record
+    #[error("Record {index} at offset {offset} is truncated")]
+    Truncated { index: usize, offset: u64 },
+    ///
+    /// The record is invalid, the iteration can go on with the next record
+    #[error("Record {index} at offset {offset} is malformed: {reason}")]
+    Malformed {
+        index: usize,
+        offset: u64,
+        reason: &'static str,
+    },
+    ///
+    /// The record can't be deserialized, the iteration can go on with the next record
+    #[cfg(feature = "serde")]
+    #[error("Record {index} at offset {offset} can't be deserialized: {source}")]
+    Deserialize {
+        index: usize,
+        offset: u64,
+        source: serde_json::Error,
+    },
+}
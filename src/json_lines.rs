@@ -0,0 +1,80 @@
+use std::io::Read;
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+use crate::AllEndOfLineMatcher;
+use crate::BufReadSplitter;
+use crate::ReaderOptions;
+use crate::RecordError;
+
+///
+/// Iterate over a newline-delimited JSON stream (NDJSON), deserializing each line into `T`
+///
+/// Blank lines are skipped. A line that can't be deserialized is returned as a
+/// `RecordError::Deserialize`, and the next call goes on with the following line.
+/// The limit of the options (see `ReaderOptions::set_limit_record`) caps the size of a line in memory.
+pub struct JsonLines<'a, T: DeserializeOwned> {
+    reader: BufReadSplitter<'a, AllEndOfLineMatcher>,
+    buf: Vec<u8>, // Line currently read, reused from one line to the other
+    index: usize, // Index of the next record
+    limit_record: Option<usize>,
+    _marker: PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned> JsonLines<'a, T> {
+    pub fn new(reader: &'a mut dyn std::io::Read, options: ReaderOptions) -> Self {
+        let mut line_options = options.splitter_options();
+        line_options.set_limit_read(options.limit_read());
+        Self {
+            reader: BufReadSplitter::new(reader, AllEndOfLineMatcher::new(), line_options),
+            buf: Vec::new(),
+            index: 0,
+            limit_record: options.limit_record,
+            _marker: PhantomData,
+        }
+    }
+}
+///
+/// Iterator implementation
+impl<'a, T: DeserializeOwned> Iterator for JsonLines<'a, T> {
+    type Item = std::result::Result<T, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.next() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(err) => return Some(Err(RecordError::Read(err))),
+            }
+            let offset = self.reader.part_offset();
+
+            self.buf.clear();
+            if let Err(err) = self.reader.read_to_end(&mut self.buf) {
+                return Some(Err(RecordError::Read(err.into())));
+            }
+            if self.buf.iter().all(u8::is_ascii_whitespace) {
+                continue; // Blank line
+            }
+
+            let index = self.index;
+            self.index += 1;
+            if self
+                .limit_record
+                .is_some_and(|limit| self.buf.len() > limit)
+            {
+                return Some(Err(RecordError::Malformed {
+                    index,
+                    offset,
+                    reason: "line too large",
+                }));
+            }
+            return Some(serde_json::from_slice(&self.buf).map_err(|source| {
+                RecordError::Deserialize {
+                    index,
+                    offset,
+                    source,
+                }
+            }));
+        }
+    }
+}
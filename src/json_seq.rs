@@ -0,0 +1,124 @@
+use std::io::Read;
+
+use crate::BufReadSplitter;
+use crate::ReaderOptions;
+use crate::RecordError;
+use crate::SimpleMatcher;
+
+///
+/// Record separator starting each record (RFC 7464)
+const RS: u8 = 0x1E;
+
+///
+/// Iterate over the records of a JSON text sequence (RFC 7464, `application/json-seq`)
+///
+/// Each record begins with RS (0x1E) and ends with LF, the iterator returns the JSON text
+/// without them. Consecutive RS are ignored. A record not ending with LF is returned as a
+/// `RecordError::Truncated`, and the text before the first RS as a `RecordError::Malformed`,
+/// the next call goes on with the following record.
+/// The limit of the options (see `ReaderOptions::set_limit_record`) caps the size of a record with its LF.
+/// After a `RecordError::Read` error (like `WouldBlock`), the next call goes on with the record being read.
+pub struct JsonSeq<'a> {
+    reader: BufReadSplitter<'a, SimpleMatcher>,
+    buf: Vec<u8>, // Record currently read, given to the caller by the iterator
+    index: usize, // Index of the next record
+    limit_record: Option<usize>,
+    first_read: bool,   // The text before the first RS is not read yet
+    read_pending: bool, // The read of the record in `buf` was stopped by an error of the stream
+}
+impl<'a> JsonSeq<'a> {
+    pub fn new(reader: &'a mut dyn std::io::Read, options: ReaderOptions) -> Self {
+        let mut record_options = options.splitter_options();
+        record_options.set_limit_read(options.limit_read());
+        Self {
+            reader: BufReadSplitter::new(reader, SimpleMatcher::new(&[RS]), record_options),
+            buf: Vec::new(),
+            index: 0,
+            limit_record: options.limit_record,
+            first_read: true,
+            read_pending: false,
+        }
+    }
+    ///
+    /// Deserialize the next record into `T`
+    #[cfg(feature = "serde")]
+    pub fn next_value<T: serde::de::DeserializeOwned>(
+        &mut self,
+    ) -> Option<std::result::Result<T, RecordError>> {
+        let (index, offset) = match self.read_record()? {
+            Ok(o) => o,
+            Err(err) => return Some(Err(err)),
+        };
+        Some(
+            serde_json::from_slice(&self.buf).map_err(|source| RecordError::Deserialize {
+                index,
+                offset,
+                source,
+            }),
+        )
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Load the next record in `buf`, return its index and its offset in the stream
+    fn read_record(&mut self) -> Option<std::result::Result<(usize, u64), RecordError>> {
+        loop {
+            if !self.read_pending {
+                match self.reader.next() {
+                    Ok(true) => {}
+                    Ok(false) => return None,
+                    Err(err) => return Some(Err(RecordError::Read(err))),
+                }
+                self.buf.clear();
+            }
+            let offset = self.reader.part_offset();
+
+            // The bytes read before an error are kept in `buf`, the read goes on after them
+            self.read_pending = true;
+            if let Err(err) = self.reader.read_to_end(&mut self.buf) {
+                return Some(Err(RecordError::Read(err.into())));
+            }
+            self.read_pending = false;
+            if self.buf.is_empty() {
+                self.first_read = false;
+                continue; // Consecutive RS, or nothing before the first one
+            }
+
+            let index = self.index;
+            self.index += 1;
+            if self.first_read {
+                self.first_read = false;
+                return Some(Err(RecordError::Malformed {
+                    index,
+                    offset,
+                    reason: "text before the first RS",
+                }));
+            }
+            if self
+                .limit_record
+                .is_some_and(|limit| self.buf.len() > limit)
+            {
+                return Some(Err(RecordError::Malformed {
+                    index,
+                    offset,
+                    reason: "record too large",
+                }));
+            }
+            return if self.buf.pop() == Some(b'\n') {
+                Some(Ok((index, offset)))
+            } else {
+                Some(Err(RecordError::Truncated { index, offset }))
+            };
+        }
+    }
+}
+///
+/// Iterator implementation
+impl<'a> Iterator for JsonSeq<'a> {
+    type Item = std::result::Result<Vec<u8>, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.read_record()?.map(|_| std::mem::take(&mut self.buf)))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::MatchResult;
+use crate::Matcher;
+
+///
+/// Default maximum depth, the same as the recursion limit of serde_json
+const DEFAULT_MAX_DEPTH: usize = 128;
+
+///
+/// Matcher ending a part right after each complete top-level JSON value, so a stream
+/// of concatenated values (`{...}{...}[...]`) can be split without separator.
+///
+/// Whitespace between two values is kept at the begin of the next part, so whitespace
+/// after the last value gives a last part without value.
+/// A value nested deeper than the maximum depth is still returned whole in its part, but
+/// `too_deep` tells it once the part is read (see `BufReadSplitter::get_matcher`), so the caller
+/// can reject it. The flag is cleared when the next part begins.
+pub struct JsonValueMatcher {
+    max_depth: usize,
+    depth: usize,    // Depth of the nested objects and arrays
+    in_string: bool, // Inside a string
+    escape: bool,    // Previous byte is an escape inside a string
+    in_scalar: bool, // Inside a top-level number or literal (true, false, null)
+    too_deep: bool,  // The value of the current part is deeper than the maximum depth
+}
+impl JsonValueMatcher {
+    pub fn new() -> Self {
+        Self::with_max_depth(DEFAULT_MAX_DEPTH)
+    }
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        Self {
+            max_depth,
+            depth: 0,
+            in_string: false,
+            escape: false,
+            in_scalar: false,
+            too_deep: false,
+        }
+    }
+    ///
+    /// Indicate that the value of the current part is nested deeper than the maximum depth
+    pub fn too_deep(&self) -> bool {
+        self.too_deep
+    }
+}
+impl Default for JsonValueMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Matcher for JsonValueMatcher {
+    // A byte ending a value is returned as `Match(1, 0)` to keep it in the part.
+    // `Mismatch` is returned for all other bytes, so the position is never used.
+    fn sequel(&mut self, el_buf: u8, _pos: usize) -> MatchResult {
+        if self.in_string {
+            if self.escape {
+                self.escape = false;
+            } else if el_buf == b'\\' {
+                self.escape = true;
+            } else if el_buf == b'"' {
+                self.in_string = false;
+                if self.depth == 0 {
+                    return MatchResult::Match(1, 0); //End of a top-level string
+                }
+            }
+            return MatchResult::Mismatch;
+        }
+        if self.in_scalar {
+            if el_buf.is_ascii_alphanumeric() || matches!(el_buf, b'+' | b'-' | b'.') {
+                return MatchResult::Mismatch;
+            }
+            // The scalar ended on the previous byte, this one is given back to the next part
+            self.in_scalar = false;
+            return MatchResult::Match(0, 1);
+        }
+        match el_buf {
+            b'{' | b'[' => {
+                self.depth += 1;
+                if self.depth > self.max_depth {
+                    self.too_deep = true;
+                }
+                MatchResult::Mismatch
+            }
+            b'}' | b']' if self.depth > 0 => {
+                self.depth -= 1;
+                if self.depth == 0 {
+                    MatchResult::Match(1, 0) //End of a top-level object or array
+                } else {
+                    MatchResult::Mismatch
+                }
+            }
+            b'"' => {
+                self.in_string = true;
+                MatchResult::Mismatch
+            }
+            b' ' | b'\t' | b'\r' | b'\n' => MatchResult::Mismatch,
+            _ => {
+                if self.depth == 0 {
+                    self.in_scalar = true;
+                }
+                MatchResult::Mismatch
+            }
+        }
+    }
+    fn reset(&mut self, _stream_start: bool) {
+        self.depth = 0;
+        self.in_string = false;
+        self.escape = false;
+        self.in_scalar = false;
+        self.too_deep = false;
+    }
+    fn part_start(&mut self) {
+        self.too_deep = false;
+    }
+}
@@ -121,12 +121,56 @@
 //!```
 //!
 //!\
+//!For fixed-width records, the parts can have a fixed size instead of being delimited (the matcher is then not used).\
+//!The last part can be shorter, or refused with an `UnexpectedEof` error :
+//!```ignore
+//!let mut reader = BufReadSplitter::new(
+//!    &mut input_reader,
+//!    SimpleMatcher::new(b""),
+//!    Options::default().set_fixed_size(Some(80)).set_fixed_size_partial(false).clone(),
+//!);
+//!```
+//!
+//!\
+//!The whitespaces around each part can be removed, and the empty parts skipped :
+//!```ignore
+//!let mut reader = BufReadSplitter::new(
+//!    &mut input_reader,
+//!    AllEndOfLineMatcher::new(),
+//!    Options::default().set_trim(true).set_skip_empty_parts(true).clone(),
+//!);
+//!```
+//!
+//!\
+//!A seekable stream can be split with `new_seekable`, to go to any part. A sparse index of the parts,
+//!built while reading, avoids to read the stream again from its begin, and can be saved for the next times :
+//!```ignore
+//!let mut reader = BufReadSplitter::new_seekable(&mut file, AllEndOfLineMatcher::new(), Options::for_files());
+//!reader.set_sparse_index(SparseIndex::new(1000)); // Position of one line every 1000 lines
+//!reader.seek_to_part(123_456)?;
+//!reader.sparse_index().unwrap().save(&mut index_file)?;
+//!```
+//!
+//!\
+//!With a non-blocking stream, `next`, `next_part` and `read` can return a `WouldBlock` error : the match in progress\
+//!is kept, so calling them again once the stream is ready resumes the split where it stopped.\
+//!`skip_parts` and `count_remaining_parts` resume the same way, the parts already gone forward are kept in their result.\
+//!An `Interrupted` error of the stream is never returned, the read is done again.
+//!
+//!\
 //!For debug purpose, a "log" features is activable in the Cargo.toml (slows down the processing) :
 //!```ignore
 //![dependencies]
 //!buf_read_splitter = {"0.4", features = ["log"] }
 //!```
 //!
+//!\
+//!To read newline-delimited JSON records with `JsonLines`, activate the "serde" feature :
+//!```ignore
+//![dependencies]
+//!buf_read_splitter = {"0.4", features = ["serde"] }
+//!```
+//!
 //!License: MIT
 //!
 
@@ -136,24 +180,72 @@ pub use all_end_of_line_matcher::AllEndOfLineMatcher;
 mod buf_read_splitter;
 pub use buf_read_splitter::BufReadSplitter;
 
+mod delimited_reader;
+pub use delimited_reader::DelimitedReader;
+
+mod json_seq;
+pub use json_seq::JsonSeq;
+
+mod json_value_matcher;
+pub use json_value_matcher::JsonValueMatcher;
+
 mod match_result;
 pub use match_result::MatchResult;
 
 mod matcher;
 pub use matcher::Matcher;
 
+mod mbox_matcher;
+pub use mbox_matcher::MboxMatcher;
+
+mod mbox_splitter;
+pub use mbox_splitter::{MboxMessage, MboxSplitter};
+
+mod nul_matcher;
+pub use nul_matcher::NulMatcher;
+
 mod options;
 pub use options::Options;
 
+mod part_info;
+pub use part_info::PartInfo;
+
+mod pem_reader;
+pub use pem_reader::{PemBlock, PemReader};
+
+mod reader_options;
+pub use reader_options::ReaderOptions;
+
 mod simple_matcher;
 pub use simple_matcher::SimpleMatcher;
 
+mod sparse_index;
+pub use sparse_index::SparseIndex;
+
+mod statistics;
+pub use statistics::Statistics;
+
+mod unicode_line_matcher;
+pub use unicode_line_matcher::UnicodeLineMatcher;
+
+mod utf16_matcher;
+pub use utf16_matcher::Utf16Matcher;
+
+mod warc_reader;
+pub use warc_reader::{WarcReader, WarcRecord};
+
 mod errors;
 pub use errors::*;
 
+#[cfg(feature = "serde")]
+mod json_lines;
+#[cfg(feature = "serde")]
+pub use json_lines::JsonLines;
+
 // private
 mod buf_ext;
 use buf_ext::BufExt;
+pub use buf_ext::ReadSeek;
 
 mod buf_growing_ext_iter;
 use buf_growing_ext_iter::BufGrowingExtIter;
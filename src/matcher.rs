@@ -3,6 +3,8 @@ use crate::MatchResult;
 pub trait Matcher {
     ///
     /// Called for each byte, return the state of the match
+    /// Each byte of the stream is given once and in order, except the bytes given back to the
+    /// next part by a `Match` (its second argument), which are given again at the begin of the next part.
     fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult;
     ///
     /// Called at the end of the stream, only if the previous byte (and so last byte) is in a NeedNext state.
@@ -11,4 +13,40 @@ pub trait Matcher {
     fn sequel_eos(&mut self, _pos: usize) -> MatchResult {
         MatchResult::Mismatch
     }
+    ///
+    /// Called when no match is in progress, return the number of bytes at the begin of `buf` that
+    /// can't begin a match, so they are skipped without calling `sequel` (and are never given to it).
+    /// This function is useful to search a separator with a fast search (like `memchr`).
+    /// Note: this is an optimization, `sequel` may still be called for each byte
+    fn skip(&mut self, _buf: &[u8]) -> usize {
+        0
+    }
+    ///
+    /// Called when the stream is read again from the begin of a part, after a seek
+    /// `stream_start` indicates that this part is the first one, at the begin of the stream.
+    /// This function is useful for a matcher keeping a state between the parts.
+    fn reset(&mut self, _stream_start: bool) {}
+    ///
+    /// Called when the next part begins, before its bytes are given to `sequel`
+    /// This function is useful for a matcher giving informations about the current part.
+    fn part_start(&mut self) {}
+}
+///
+/// A boxed matcher, so the kind of matcher can be changed on the fly with a `BufReadSplitter<Box<dyn Matcher>>`
+impl<M: Matcher + ?Sized> Matcher for Box<M> {
+    fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult {
+        (**self).sequel(el_buf, pos)
+    }
+    fn sequel_eos(&mut self, pos: usize) -> MatchResult {
+        (**self).sequel_eos(pos)
+    }
+    fn skip(&mut self, buf: &[u8]) -> usize {
+        (**self).skip(buf)
+    }
+    fn reset(&mut self, stream_start: bool) {
+        (**self).reset(stream_start)
+    }
+    fn part_start(&mut self) {
+        (**self).part_start()
+    }
 }
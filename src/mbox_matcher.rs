@@ -0,0 +1,65 @@
+use crate::MatchResult;
+use crate::Matcher;
+
+const FROM_LINE: &[u8] = b"\nFrom ";
+
+///
+/// Matcher for the `From ` lines separating the messages of a mbox mailbox
+///
+/// It matches `\nFrom ` (the end of line is the separator, `From ` is kept at the begin of
+/// the next part), and `From ` at the very first byte of the stream, so the first part is
+/// always the text before the first message (usually empty).
+pub struct MboxMatcher {
+    at_start: bool, // Nothing has been read yet
+    start: usize,   // Position where the pattern begins in the current match
+    shift: usize,   // 1 if the pattern begins without end of line (begin of the stream)
+}
+impl MboxMatcher {
+    pub fn new() -> Self {
+        Self {
+            at_start: true,
+            start: 0,
+            shift: 0,
+        }
+    }
+}
+impl Default for MboxMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Matcher for MboxMatcher {
+    fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult {
+        if pos == 0 {
+            self.start = 0;
+            self.shift = if self.at_start && el_buf == b'F' {
+                1
+            } else {
+                0
+            };
+            self.at_start = false;
+        }
+        let idx = pos - self.start + self.shift;
+        if el_buf == FROM_LINE[idx] {
+            if idx + 1 == FROM_LINE.len() {
+                // Bytes before the end of line go to the content, `From ` goes to the next part
+                MatchResult::Match(self.start, FROM_LINE.len() - 1)
+            } else {
+                MatchResult::NeedNext
+            }
+        } else if el_buf == b'\n' {
+            // An empty line : the pattern can begin again here
+            self.start = pos;
+            self.shift = 0;
+            MatchResult::NeedNext
+        } else {
+            MatchResult::Mismatch
+        }
+    }
+    fn reset(&mut self, stream_start: bool) {
+        // `From ` at the begin of another part is already the begin of a message
+        self.at_start = stream_start;
+        self.start = 0;
+        self.shift = 0;
+    }
+}
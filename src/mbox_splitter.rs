@@ -0,0 +1,127 @@
+use std::io::Read;
+
+use crate::BufReadSplitter;
+use crate::MboxMatcher;
+use crate::ReaderOptions;
+use crate::RecordError;
+
+///
+/// A message of a mbox mailbox
+#[derive(Debug, Clone)]
+pub struct MboxMessage {
+    ///
+    /// The envelope line, without `From ` and without the end of line
+    pub envelope: Vec<u8>,
+    ///
+    /// The message itself (headers and body)
+    pub body: Vec<u8>,
+}
+
+///
+/// Iterate over the messages of a mbox mailbox, the text before the first `From ` line is ignored
+///
+/// In mboxrd mode (see `set_mboxrd`), the `>From ` lines of the messages are unquoted.
+/// The last message keeps the empty line ending the mailbox.
+/// The limit of the options (see `ReaderOptions::set_limit_record`) caps the size of a message with its
+/// envelope line.
+pub struct MboxSplitter<'a> {
+    reader: BufReadSplitter<'a, MboxMatcher>,
+    mboxrd: bool,
+    first_read: bool,
+    index: usize, // Index of the next message
+    limit_record: Option<usize>,
+}
+impl<'a> MboxSplitter<'a> {
+    pub fn new(reader: &'a mut dyn std::io::Read, options: ReaderOptions) -> Self {
+        let mut message_options = options.splitter_options();
+        message_options.set_limit_read(options.limit_read());
+        Self {
+            reader: BufReadSplitter::new(reader, MboxMatcher::new(), message_options),
+            mboxrd: false,
+            first_read: true,
+            index: 0,
+            limit_record: options.limit_record,
+        }
+    }
+    ///
+    /// Unquote the `>From ` lines (mboxrd format)
+    pub fn set_mboxrd(&mut self, mboxrd: bool) {
+        self.mboxrd = mboxrd;
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Go to the next message, return false at the end of the stream
+    fn next_message(&mut self) -> std::result::Result<bool, RecordError> {
+        if self.first_read {
+            self.first_read = false;
+            // The first part is the text before the first message
+            if !self.reader.next().map_err(RecordError::Read)? {
+                return Ok(false);
+            }
+        }
+        self.reader.next().map_err(RecordError::Read)
+    }
+}
+///
+/// Iterator implementation
+impl<'a> Iterator for MboxSplitter<'a> {
+    type Item = std::result::Result<MboxMessage, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_message() {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(err) => return Some(Err(err)),
+        }
+
+        let index = self.index;
+        self.index += 1;
+        let offset = self.reader.part_offset();
+
+        let mut part = Vec::new();
+        if let Err(err) = self.reader.read_to_end(&mut part) {
+            return Some(Err(RecordError::Read(err.into())));
+        }
+        if self.limit_record.is_some_and(|limit| part.len() > limit) {
+            return Some(Err(RecordError::Malformed {
+                index,
+                offset,
+                reason: "message too large",
+            }));
+        }
+
+        // Split the envelope line
+        let (line, body) = match part.iter().position(|c| *c == b'\n') {
+            Some(pos) => (&part[..pos], &part[pos + 1..]),
+            None => (&part[..], &part[part.len()..]),
+        };
+        let line = line.strip_prefix(b"From ").unwrap_or(line);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        Some(Ok(MboxMessage {
+            envelope: line.to_vec(),
+            body: if self.mboxrd {
+                unquote_from(body)
+            } else {
+                body.to_vec()
+            },
+        }))
+    }
+}
+
+///
+/// Remove one `>` of the lines beginning with `>From `, `>>From `, ...
+fn unquote_from(body: &[u8]) -> Vec<u8> {
+    let mut unquoted = Vec::with_capacity(body.len());
+    for line in body.split_inclusive(|c| *c == b'\n') {
+        let quotes = line.iter().take_while(|c| **c == b'>').count();
+        if quotes > 0 && line[quotes..].starts_with(b"From ") {
+            unquoted.extend_from_slice(&line[1..]);
+        } else {
+            unquoted.extend_from_slice(line);
+        }
+    }
+    unquoted
+}
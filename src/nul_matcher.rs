@@ -0,0 +1,31 @@
+use crate::MatchResult;
+use crate::Matcher;
+
+///
+/// Matcher for NUL (`\0`) terminated records, like the output of `find -print0` or `xargs -0` inputs
+///
+/// The NUL byte is searched with `memchr`, so the bytes between two separators are not given one by one.
+#[derive(Clone)]
+pub struct NulMatcher;
+impl NulMatcher {
+    pub fn new() -> Self {
+        Self
+    }
+}
+impl Default for NulMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Matcher for NulMatcher {
+    fn sequel(&mut self, el_buf: u8, _pos: usize) -> MatchResult {
+        if el_buf == 0 {
+            MatchResult::Match(0, 0)
+        } else {
+            MatchResult::Mismatch
+        }
+    }
+    fn skip(&mut self, buf: &[u8]) -> usize {
+        memchr::memchr(0, buf).unwrap_or(buf.len())
+    }
+}
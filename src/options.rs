@@ -5,21 +5,54 @@ use core::fmt;
 pub struct Options {
     pub(crate) initiale_sz_to_match: usize,
     pub(crate) chunk_sz: usize,
+    pub(crate) chunk_max_sz: Option<usize>,
     pub(crate) limit_read: Option<usize>,
+    pub(crate) fixed_size: Option<usize>,
+    pub(crate) fixed_size_partial: bool,
+    pub(crate) skip_empty_parts: bool,
+    pub(crate) trim: Option<Vec<u8>>,
 }
 ///
-/// Options implementations
-impl Options {
-    ///
-    /// Options by defaults
-    pub fn default() -> Self {
+/// Options by defaults
+impl Default for Options {
+    fn default() -> Self {
         let approximate_pattern_sz = 10;
         Self {
             initiale_sz_to_match: approximate_pattern_sz,
             chunk_sz: 5,
+            chunk_max_sz: None,
             limit_read: None,
+            fixed_size: None,
+            fixed_size_partial: true,
+            skip_empty_parts: false,
+            trim: None,
         }
     }
+}
+///
+/// Options implementations
+impl Options {
+    ///
+    /// Options for files : the reads are large since the bytes are available
+    pub fn for_files() -> Self {
+        let mut options = Self::default();
+        options
+            .set_reserve_sz_to_match(8 * 1024)
+            .set_extend_buffer_additionnal_sz(4 * 1024)
+            .set_adaptive_extend_buffer_max_sz(Some(256 * 1024));
+        options
+    }
+    ///
+    /// Options for sockets : the reads begin small to not wait for bytes not needed, and grow if the
+    /// bytes keep coming
+    pub fn for_sockets() -> Self {
+        let mut options = Self::default();
+        options
+            .set_reserve_sz_to_match(1024)
+            .set_extend_buffer_additionnal_sz(512)
+            .set_adaptive_extend_buffer_max_sz(Some(64 * 1024));
+        options
+    }
     ///
     /// Set the initiale size of the pattern to match
     /// This sets the initiale size of the extending buffer needed to read over the reading buffer
@@ -34,15 +67,61 @@ impl Options {
         self
     }
     ///
+    /// Let the size of each extension grow up to `opt_sz` (None to keep it constant)
+    /// The size is doubled each time a read fills the whole extension, and goes back to the size set
+    /// with `set_extend_buffer_additionnal_sz` once the stream is read again without the extending buffer.
+    pub fn set_adaptive_extend_buffer_max_sz(&mut self, opt_sz: Option<usize>) -> &mut Self {
+        self.chunk_max_sz = opt_sz;
+        self
+    }
+    ///
     /// Set a limit of bytes to read of a buffer part
     pub fn set_limit_read(&mut self, opt_sz: Option<usize>) -> &mut Self {
         self.limit_read = opt_sz;
         self
     }
+    ///
+    /// Set a fixed size for all the parts, so the stream is split every `sz` bytes and the matcher is not used
+    /// The size must be greater than 0
+    pub fn set_fixed_size(&mut self, opt_sz: Option<usize>) -> &mut Self {
+        assert!(opt_sz != Some(0), "A fixed size must be greater than 0");
+        self.fixed_size = opt_sz;
+        self
+    }
+    ///
+    /// Accept (by default) a last part shorter than the fixed size
+    /// When refused, reading the end of a such part returns an `UnexpectedEof` error
+    pub fn set_fixed_size_partial(&mut self, accept: bool) -> &mut Self {
+        self.fixed_size_partial = accept;
+        self
+    }
+    ///
+    /// Skip the parts of zero length (like consecutive separators), so `next` and `next_part` go directly to
+    /// the next non-empty part
+    pub fn set_skip_empty_parts(&mut self, skip: bool) -> &mut Self {
+        self.skip_empty_parts = skip;
+        self
+    }
+    ///
+    /// Remove the ASCII whitespaces (see `u8::is_ascii_whitespace`) at the begin and at the end of each part
+    pub fn set_trim(&mut self, trim: bool) -> &mut Self {
+        self.trim = if trim {
+            Some(b" \t\n\x0C\r".to_vec())
+        } else {
+            None
+        };
+        self
+    }
+    ///
+    /// Remove the given bytes at the begin and at the end of each part
+    pub fn set_trim_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.trim = Some(bytes.to_vec());
+        self
+    }
 }
 ///
 /// Debug
-impl<'a> fmt::Debug for Options {
+impl fmt::Debug for Options {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
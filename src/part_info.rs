@@ -0,0 +1,11 @@
+///
+/// Informations about a part, see `BufReadSplitter::set_matcher_fn`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartInfo {
+    ///
+    /// Position in the stream of the first byte of the part
+    pub offset: u64,
+    ///
+    /// Length in the stream of the part, including its separator
+    pub len: u64,
+}
@@ -0,0 +1,202 @@
+use std::io::Read;
+
+use crate::BufReadSplitter;
+use crate::ReaderOptions;
+use crate::RecordError;
+use crate::SimpleMatcher;
+
+const BEGIN: &[u8] = b"-----BEGIN ";
+const END: &[u8] = b"-----END ";
+const DASHES: &[u8] = b"-----";
+///
+/// Maximum size of a begin or end line, a longer line is not a block boundary
+const MAX_LINE_SZ: usize = 256;
+
+///
+/// A PEM block
+#[derive(Debug, Clone)]
+pub struct PemBlock {
+    ///
+    /// The label, as `CERTIFICATE` in `-----BEGIN CERTIFICATE-----`
+    pub label: String,
+    ///
+    /// The decoded content (DER)
+    pub der: Vec<u8>,
+}
+
+///
+/// Iterate over the PEM blocks (`-----BEGIN <label>-----` ... `-----END <label>-----`) of a text stream
+///
+/// The begin and end lines are only recognized at the start of a line, and the text between the
+/// blocks is ignored. A block with an invalid content is returned as a `RecordError::Malformed`,
+/// and a block without end line as a `RecordError::Truncated`, the next call goes on with the
+/// following block.
+/// The limit of the options (see `ReaderOptions::set_limit_record`) caps the size of the base64
+/// content of a block, with its ends of line.
+pub struct PemReader<'a> {
+    reader: BufReadSplitter<'a, SimpleMatcher>, // Split the lines
+    index: usize,                               // Index of the next block
+    line_pending: bool, // The check of the current line was stopped by an error of the stream
+    limit_record: Option<usize>,
+}
+impl<'a> PemReader<'a> {
+    pub fn new(reader: &'a mut dyn std::io::Read, options: ReaderOptions) -> Self {
+        Self {
+            reader: BufReadSplitter::new(
+                reader,
+                SimpleMatcher::new(b"\n"),
+                options.splitter_options(),
+            ),
+            index: 0,
+            line_pending: false,
+            limit_record: options.limit_record,
+        }
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Read the next block, the reader is left on the line after it
+    fn next_block(&mut self) -> std::result::Result<Option<PemBlock>, RecordError> {
+        // Skip the text until a begin line
+        let label = loop {
+            if !self.next_line()? {
+                return Ok(None);
+            }
+            if let Some(label) = self.read_marker(BEGIN)? {
+                break label;
+            }
+        };
+        let index = self.index;
+        self.index += 1;
+        let offset = self.reader.part_offset();
+        let malformed = |reason| RecordError::Malformed {
+            index,
+            offset,
+            reason,
+        };
+
+        let mut content = Vec::new();
+        let mut too_large = false; // The content is over the limit, the lines are skipped until the end line
+        let end_label = loop {
+            if !self.next_line()? {
+                return Err(RecordError::Truncated { index, offset });
+            }
+            if let Some(end_label) = self.read_marker(END)? {
+                break end_label;
+            }
+            if self.read_marker(BEGIN)?.is_some() {
+                // The block is not ended, the next call begins the new block from this line
+                self.line_pending = true;
+                return Err(RecordError::Truncated { index, offset });
+            }
+            if too_large {
+                continue;
+            }
+            // One byte over the limit at most is read
+            let remaining = self
+                .limit_record
+                .map_or(u64::MAX, |limit| (limit - content.len()) as u64 + 1);
+            (&mut self.reader)
+                .take(remaining)
+                .read_to_end(&mut content)
+                .map_err(|err| RecordError::Read(err.into()))?;
+            if self.limit_record.is_some_and(|limit| content.len() > limit) {
+                too_large = true;
+                content = Vec::new();
+            }
+        };
+
+        if too_large {
+            return Err(malformed("block too large"));
+        }
+
+        if label != end_label {
+            return Err(malformed("the end label differs from the begin label"));
+        }
+        let label = String::from_utf8(label).map_err(|_| malformed("invalid label"))?;
+        let der = decode_base64(&content).ok_or_else(|| malformed("invalid base64 content"))?;
+        Ok(Some(PemBlock { label, der }))
+    }
+    ///
+    /// Return the label if the current line is `marker` followed by a label and dashes
+    /// The line is left unread, so it can be checked again after an error of the stream.
+    fn read_marker(&mut self, marker: &[u8]) -> std::result::Result<Option<Vec<u8>>, RecordError> {
+        self.line_pending = true;
+        let mut line = self
+            .reader
+            .peek(MAX_LINE_SZ + 1)
+            .map_err(RecordError::Read)?;
+        self.line_pending = false;
+
+        if line.last() == Some(&b'\r') {
+            line = &line[..line.len() - 1];
+        }
+        if line.len() > MAX_LINE_SZ
+            || line.len() < marker.len() + DASHES.len()
+            || !line.starts_with(marker)
+            || !line.ends_with(DASHES)
+        {
+            return Ok(None);
+        }
+        Ok(Some(line[marker.len()..line.len() - DASHES.len()].to_vec()))
+    }
+    ///
+    /// Go to the next line, return false at the end of the stream
+    fn next_line(&mut self) -> std::result::Result<bool, RecordError> {
+        if self.line_pending {
+            self.line_pending = false;
+            return Ok(true); // The current line is checked again
+        }
+        self.reader.next().map_err(RecordError::Read)
+    }
+}
+///
+/// Iterator implementation
+impl<'a> Iterator for PemReader<'a> {
+    type Item = std::result::Result<PemBlock, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_block().transpose()
+    }
+}
+
+///
+/// Decode a base64 text, whitespaces are ignored
+fn decode_base64(text: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(text.len() / 4 * 3);
+    let mut acc = 0u32; // Bits not yet decoded
+    let mut nb_bits = 0; // Number of bits in `acc`
+    let mut padding = 0;
+    for c in text {
+        let val = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => {
+                padding += 1;
+                continue;
+            }
+            b' ' | b'\t' | b'\r' | b'\n' => continue,
+            _ => return None,
+        };
+        if padding > 0 {
+            return None; // Nothing is expected after the padding
+        }
+        acc = (acc << 6) | val as u32;
+        nb_bits += 6;
+        if nb_bits >= 8 {
+            nb_bits -= 8;
+            decoded.push((acc >> nb_bits) as u8);
+            acc &= (1 << nb_bits) - 1;
+        }
+    }
+    // A single character can't encode a byte
+    if padding > 2 || nb_bits == 6 {
+        None
+    } else {
+        Some(decoded)
+    }
+}
@@ -6,7 +6,7 @@ pub struct PosSizeHelper {
 
 impl PosSizeHelper {
     ///
-    ///
+    /// Positions computed from a match, `end_of_sep_pos` is the position of the last byte matched
     pub fn from_match(
         take_left: usize,
         take_right: usize,
@@ -20,39 +20,21 @@ impl PosSizeHelper {
         }
     }
     ///
-    ///
+    /// Same positions, but relative to `relative_pos`
     pub fn from_relative(pos_sz: &PosSizeHelper, relative_pos: usize) -> Self {
         Self {
-            next_content_pos: {
-                if pos_sz.next_content_pos > relative_pos {
-                    pos_sz.next_content_pos - relative_pos
-                } else {
-                    0
-                }
-            },
-            skipped_pos: {
-                if pos_sz.skipped_pos > relative_pos {
-                    pos_sz.skipped_pos - relative_pos
-                } else {
-                    0
-                }
-            },
+            next_content_pos: pos_sz.next_content_pos.saturating_sub(relative_pos),
+            skipped_pos: pos_sz.skipped_pos.saturating_sub(relative_pos),
         }
     }
     ///
-    ///
+    /// Position of the next content
     pub fn next_content_pos(&self) -> usize {
         self.next_content_pos
     }
     ///
-    ///
+    /// Position of the skipped part (the separator)
     pub fn skipped_pos(&self) -> usize {
         self.skipped_pos
     }
-
-    ///
-    ///
-    pub fn skipped_len(&self) -> usize {
-        self.next_content_pos - self.skipped_pos
-    }
 }
@@ -0,0 +1,107 @@
+use core::fmt;
+
+use crate::Options;
+///
+/// Options for the readers of records built on a BufReadSplitter (`JsonLines`, `PemReader`, ...)
+///
+/// Only the sizes of the buffers and a limit of the size of a record can be set, the other options
+/// of `Options` would change the parts the readers parse.
+#[derive(Clone)]
+pub struct ReaderOptions {
+    pub(crate) initiale_sz_to_match: usize,
+    pub(crate) chunk_sz: usize,
+    pub(crate) chunk_max_sz: Option<usize>,
+    pub(crate) limit_record: Option<usize>,
+}
+///
+/// Options by defaults
+impl Default for ReaderOptions {
+    fn default() -> Self {
+        let options = Options::default();
+        Self {
+            initiale_sz_to_match: options.initiale_sz_to_match,
+            chunk_sz: options.chunk_sz,
+            chunk_max_sz: options.chunk_max_sz,
+            limit_record: None,
+        }
+    }
+}
+///
+/// ReaderOptions implementations
+impl ReaderOptions {
+    ///
+    /// Options for files, with the buffer sizes of `Options::for_files`
+    pub fn for_files() -> Self {
+        Self::with_buffer_sizes(&Options::for_files())
+    }
+    ///
+    /// Options for sockets, with the buffer sizes of `Options::for_sockets`
+    pub fn for_sockets() -> Self {
+        Self::with_buffer_sizes(&Options::for_sockets())
+    }
+    ///
+    /// Set the initiale size of the pattern to match, see `Options::set_reserve_sz_to_match`
+    pub fn set_reserve_sz_to_match(&mut self, sz: usize) -> &mut Self {
+        self.initiale_sz_to_match = sz;
+        self
+    }
+    ///
+    /// Set the size of each extension of the extending buffer, see `Options::set_extend_buffer_additionnal_sz`
+    pub fn set_extend_buffer_additionnal_sz(&mut self, sz: usize) -> &mut Self {
+        self.chunk_sz = sz;
+        self
+    }
+    ///
+    /// Let the size of each extension grow up to `opt_sz`, see `Options::set_adaptive_extend_buffer_max_sz`
+    pub fn set_adaptive_extend_buffer_max_sz(&mut self, opt_sz: Option<usize>) -> &mut Self {
+        self.chunk_max_sz = opt_sz;
+        self
+    }
+    ///
+    /// Set a limit of bytes of a record kept in memory (None for no limit)
+    /// A larger record is returned as a `RecordError::Malformed`, and the next call goes on with the
+    /// following record. Each reader tells what its limit covers.
+    pub fn set_limit_record(&mut self, opt_sz: Option<usize>) -> &mut Self {
+        self.limit_record = opt_sz;
+        self
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Same sizes of the buffers as `options`, and no limit
+    fn with_buffer_sizes(options: &Options) -> Self {
+        Self {
+            initiale_sz_to_match: options.initiale_sz_to_match,
+            chunk_sz: options.chunk_sz,
+            chunk_max_sz: options.chunk_max_sz,
+            limit_record: None,
+        }
+    }
+    ///
+    /// Options of the splitter of a reader, only the sizes of the buffers are set
+    pub(crate) fn splitter_options(&self) -> Options {
+        let mut options = Options::default();
+        options
+            .set_reserve_sz_to_match(self.initiale_sz_to_match)
+            .set_extend_buffer_additionnal_sz(self.chunk_sz)
+            .set_adaptive_extend_buffer_max_sz(self.chunk_max_sz);
+        options
+    }
+    ///
+    /// Limit of read of a record, one byte over the limit to detect the larger records
+    pub(crate) fn limit_read(&self) -> Option<usize> {
+        self.limit_record.map(|sz| sz.saturating_add(1))
+    }
+}
+///
+/// Debug
+impl fmt::Debug for ReaderOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "approximate_pattern_sz={}, chunk_sz={}, limit_record={:?},",
+            self.initiale_sz_to_match, self.chunk_sz, self.limit_record
+        )
+    }
+}
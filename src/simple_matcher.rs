@@ -1,6 +1,7 @@
 use crate::MatchResult;
 use crate::Matcher;
 
+#[derive(Clone)]
 pub struct SimpleMatcher {
     to_match: Vec<u8>,
 }
@@ -13,15 +14,13 @@ impl SimpleMatcher {
 }
 impl Matcher for SimpleMatcher {
     fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult {
-        if cfg!(debug_assertions) {
-            if pos > self.to_match.len() {
-                panic!(
-                    "Line {} : Unexpected overflow : {} > {}",
-                    line!(),
-                    pos,
-                    self.to_match.len()
-                );
-            }
+        if cfg!(debug_assertions) && pos > self.to_match.len() {
+            panic!(
+                "Line {} : Unexpected overflow : {} > {}",
+                line!(),
+                pos,
+                self.to_match.len()
+            );
         }
         if pos == self.to_match.len() || el_buf != *self.to_match.get(pos).unwrap() {
             MatchResult::Mismatch
@@ -31,4 +30,10 @@ impl Matcher for SimpleMatcher {
             MatchResult::NeedNext
         }
     }
+    fn skip(&mut self, buf: &[u8]) -> usize {
+        match self.to_match.first() {
+            Some(first) => memchr::memchr(*first, buf).unwrap_or(buf.len()),
+            None => buf.len(),
+        }
+    }
 }
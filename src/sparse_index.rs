@@ -0,0 +1,99 @@
+use std::{
+    cmp,
+    io::{Read, Write},
+};
+
+///
+/// Header of a saved index
+const MAGIC: &[u8; 4] = b"BRSI";
+
+///
+/// Position in the stream of every `interval`-th part, see `BufReadSplitter::set_sparse_index`
+///
+/// The positions are counted from the position of the stream when the splitting began, and the
+/// parts are counted like `next` returns them (the empty parts skipped are not counted).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseIndex {
+    interval: usize,
+    offsets: Vec<u64>, // Position of the parts 0, interval, 2*interval...
+}
+impl SparseIndex {
+    ///
+    /// Empty index, keeping the position of one part every `interval` parts
+    /// The interval must be greater than 0
+    pub fn new(interval: usize) -> Self {
+        assert!(interval > 0, "The interval must be greater than 0");
+        Self {
+            interval,
+            offsets: Vec::new(),
+        }
+    }
+    ///
+    /// Number of parts between two positions kept
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+    ///
+    /// Positions of the parts 0, `interval`, 2 * `interval`... known so far
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+    ///
+    /// Write the index, to load it again with `load`
+    pub fn save(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(self.interval as u64).to_le_bytes())?;
+        writer.write_all(&(self.offsets.len() as u64).to_le_bytes())?;
+        for offset in &self.offsets {
+            writer.write_all(&offset.to_le_bytes())?;
+        }
+        Ok(())
+    }
+    ///
+    /// Read an index written by `save`
+    pub fn load(reader: &mut dyn Read) -> std::io::Result<Self> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(Self::invalid("Not a saved index"));
+        }
+        let interval = Self::read_u64(reader)? as usize;
+        if interval == 0 {
+            return Err(Self::invalid("The interval of the index is 0"));
+        }
+        let count = Self::read_u64(reader)?;
+        let mut offsets = Vec::new();
+        for _ in 0..count {
+            let offset = Self::read_u64(reader)?;
+            if offsets.last().is_some_and(|last| *last > offset) {
+                return Err(Self::invalid("The positions of the index are not in order"));
+            }
+            offsets.push(offset);
+        }
+        Ok(Self { interval, offsets })
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Keep the position of the part `index` if it's the next one expected
+    pub(crate) fn record(&mut self, index: usize, offset: u64) {
+        if index.is_multiple_of(self.interval) && index / self.interval == self.offsets.len() {
+            self.offsets.push(offset);
+        }
+    }
+    ///
+    /// The closest part known before the part `index`, with its position
+    pub(crate) fn closest(&self, index: usize) -> Option<(usize, u64)> {
+        let nth = cmp::min(index / self.interval, self.offsets.len().checked_sub(1)?);
+        Some((nth * self.interval, self.offsets[nth]))
+    }
+    fn read_u64(reader: &mut dyn Read) -> std::io::Result<u64> {
+        let mut bytes = [0u8; 8];
+        reader.read_exact(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+    fn invalid(msg: &'static str) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, msg)
+    }
+}
@@ -0,0 +1,8 @@
+///
+/// Statistics of a BufReadSplitter, see `BufReadSplitter::statistics`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Statistics {
+    ///
+    /// Number of empty parts skipped (see `Options::set_skip_empty_parts`)
+    pub skipped_empty_parts: u64,
+}
@@ -0,0 +1,106 @@
+use crate::MatchResult;
+use crate::Matcher;
+
+///
+/// Bytes of a line break read so far
+enum State {
+    None,
+    Cr,    // \r, can be followed by \n
+    C2,    // First byte of NEL
+    E2,    // First byte of LS or PS
+    E2_80, // First two bytes of LS or PS
+}
+
+///
+/// Matcher for all the mandatory line breaks of UAX #14 in an UTF-8 stream :
+/// `\r\n`, `\r`, `\n`, VT (`\x0B`), FF (`\x0C`), NEL (U+0085), LS (U+2028) and PS (U+2029)
+///
+/// An invalid UTF-8 sequence is kept in the part, a line break following it is still found.
+pub struct UnicodeLineMatcher {
+    state: State,
+    start: usize, // Position where the line break begins in the current match
+}
+impl UnicodeLineMatcher {
+    pub fn new() -> Self {
+        Self {
+            state: State::None,
+            start: 0,
+        }
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Byte which can begin a line break
+    fn begin(&mut self, el_buf: u8) -> MatchResult {
+        self.state = match el_buf {
+            b'\n' | 0x0B | 0x0C => return MatchResult::Match(self.start, 0),
+            b'\r' => State::Cr,
+            0xC2 => State::C2,
+            0xE2 => State::E2,
+            _ => return MatchResult::Mismatch,
+        };
+        MatchResult::NeedNext
+    }
+    ///
+    /// The line break in progress is not one, this byte can begin another one
+    fn restart(&mut self, el_buf: u8, pos: usize) -> MatchResult {
+        self.start = pos;
+        self.begin(el_buf)
+    }
+}
+impl Default for UnicodeLineMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Matcher for UnicodeLineMatcher {
+    fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult {
+        if pos == 0 {
+            self.start = 0;
+            self.state = State::None;
+        }
+        match self.state {
+            State::None => self.begin(el_buf),
+            State::Cr => {
+                if el_buf == b'\n' {
+                    MatchResult::Match(self.start, 0) //We are on \r\n
+                } else {
+                    MatchResult::Match(self.start, 1) //The \r is alone, the last byte is given back
+                }
+            }
+            State::C2 => {
+                if el_buf == 0x85 {
+                    MatchResult::Match(self.start, 0)
+                } else {
+                    self.restart(el_buf, pos)
+                }
+            }
+            State::E2 => {
+                if el_buf == 0x80 {
+                    self.state = State::E2_80;
+                    MatchResult::NeedNext
+                } else {
+                    self.restart(el_buf, pos)
+                }
+            }
+            State::E2_80 => {
+                if el_buf == 0xA8 || el_buf == 0xA9 {
+                    MatchResult::Match(self.start, 0)
+                } else {
+                    self.restart(el_buf, pos)
+                }
+            }
+        }
+    }
+    fn sequel_eos(&mut self, _pos: usize) -> MatchResult {
+        match self.state {
+            State::Cr => MatchResult::Match(self.start, 0), //The last byte is \r
+            _ => MatchResult::Mismatch,
+        }
+    }
+    fn reset(&mut self, _stream_start: bool) {
+        self.state = State::None;
+        self.start = 0;
+    }
+}
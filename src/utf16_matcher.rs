@@ -0,0 +1,132 @@
+use crate::MatchResult;
+use crate::Matcher;
+
+///
+/// Matcher for a separator in an UTF-16 encoded stream
+///
+/// The separator is only matched on 2-bytes boundaries (counted from the first byte given to the matcher),
+/// so a separator is never found across two code units.
+/// The stream is read as little-endian (`new`) or big-endian (`new_big_endian`), unless it begins with
+/// a BOM (byte order mark) which selects the endianness. The BOM is kept at the begin of the first part.
+pub struct Utf16Matcher {
+    separator: Vec<u16>,  // Code units of the separator
+    fallback: Vec<usize>, // Number of units still matched after a mismatch at each position (KMP)
+    big_endian: bool,
+    first_unit: bool,    // The first code unit is not complete yet
+    odd: bool,           // The next byte is the second byte of a code unit
+    prev_byte: u8,       // First byte of the current code unit
+    unit_matched: usize, // Number of code units of the separator matched
+}
+impl Utf16Matcher {
+    ///
+    /// Little-endian by default
+    pub fn new(separator: &str) -> Self {
+        Self::with_endianness(separator, false)
+    }
+    ///
+    /// Big-endian by default
+    pub fn new_big_endian(separator: &str) -> Self {
+        Self::with_endianness(separator, true)
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    fn with_endianness(separator: &str, big_endian: bool) -> Self {
+        let separator: Vec<u16> = separator.encode_utf16().collect();
+        assert!(!separator.is_empty(), "The separator can't be empty");
+
+        let mut fallback = vec![0usize; separator.len()];
+        let mut k = 0;
+        for i in 1..separator.len() {
+            while k > 0 && separator[i] != separator[k] {
+                k = fallback[k - 1];
+            }
+            if separator[i] == separator[k] {
+                k += 1;
+            }
+            fallback[i] = k;
+        }
+
+        Self {
+            separator,
+            fallback,
+            big_endian,
+            first_unit: true,
+            odd: false,
+            prev_byte: 0,
+            unit_matched: 0,
+        }
+    }
+    ///
+    /// Byte expected at the begin of a code unit
+    fn first_byte(&self, unit: u16) -> u8 {
+        if self.big_endian {
+            unit.to_be_bytes()[0]
+        } else {
+            unit.to_le_bytes()[0]
+        }
+    }
+}
+impl Matcher for Utf16Matcher {
+    // The bytes of a partial match are kept in a `NeedNext` state, even the ones which no more
+    // belong to the separator after a mismatch, so they are given back with the left size of the `Match`.
+    fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult {
+        if !self.odd {
+            self.odd = true;
+            self.prev_byte = el_buf;
+            if self.unit_matched > 0
+                || self.first_unit
+                || el_buf == self.first_byte(self.separator[0])
+            {
+                MatchResult::NeedNext
+            } else {
+                // The code unit can't begin the separator (its second byte will mismatch too)
+                MatchResult::Mismatch
+            }
+        } else {
+            self.odd = false;
+            let unit = if self.big_endian {
+                u16::from_be_bytes([self.prev_byte, el_buf])
+            } else {
+                u16::from_le_bytes([self.prev_byte, el_buf])
+            };
+            if self.first_unit {
+                self.first_unit = false;
+                match (self.prev_byte, el_buf) {
+                    (0xFF, 0xFE) => {
+                        self.big_endian = false;
+                        return MatchResult::Mismatch;
+                    }
+                    (0xFE, 0xFF) => {
+                        self.big_endian = true;
+                        return MatchResult::Mismatch;
+                    }
+                    _ => {}
+                }
+            }
+
+            while self.unit_matched > 0 && unit != self.separator[self.unit_matched] {
+                self.unit_matched = self.fallback[self.unit_matched - 1];
+            }
+            if unit == self.separator[self.unit_matched] {
+                self.unit_matched += 1;
+            }
+
+            if self.unit_matched == self.separator.len() {
+                self.unit_matched = 0;
+                // The bytes before the separator stay in the part
+                MatchResult::Match(pos + 1 - 2 * self.separator.len(), 0)
+            } else if self.unit_matched > 0 {
+                MatchResult::NeedNext
+            } else {
+                MatchResult::Mismatch
+            }
+        }
+    }
+    fn reset(&mut self, stream_start: bool) {
+        // A part begins at the begin of a code unit, the endianness already found is kept
+        self.first_unit = stream_start;
+        self.odd = false;
+        self.unit_matched = 0;
+    }
+}
@@ -0,0 +1,300 @@
+use std::io::Read;
+
+use crate::BufReadSplitter;
+use crate::MatchResult;
+use crate::Matcher;
+use crate::ReaderOptions;
+use crate::RecordError;
+use crate::SimpleMatcher;
+
+const END_OF_HEADER: &[u8] = b"\r\n\r\n";
+///
+/// Begin of a record at the begin of a line, to find the next record after an invalid one
+const VERSION_LINE: &[u8] = b"\nWARC/";
+///
+/// Maximum size of a header block, unless a limit is set in the options
+const MAX_HEADER_SZ: usize = 64 * 1024;
+
+///
+/// Header block of a WARC record
+#[derive(Debug, Clone)]
+pub struct WarcRecord {
+    ///
+    /// Version line, as `WARC/1.1`
+    pub version: String,
+    ///
+    /// Header fields, in the order of the stream
+    pub fields: Vec<(String, String)>,
+    ///
+    /// Size of the payload (`Content-Length`)
+    pub content_length: u64,
+    ///
+    /// Position in the stream of the record
+    pub offset: u64,
+}
+impl WarcRecord {
+    ///
+    /// Value of a header field, the name is case insensitive
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+///
+/// Where the reader is in the current record
+#[derive(PartialEq)]
+enum Position {
+    Header,
+    Payload,
+    Trailer,
+    Invalid, // The header is invalid, the rest of the record is skipped
+}
+
+///
+/// Splits the header blocks on the end of header, the payloads on their size, and the trailers
+/// (or the rest of an invalid record) on the next version line
+enum WarcMatcher {
+    Header(SimpleMatcher),
+    Payload(u64),      // Bytes remaining in the payload
+    NextRecord(usize), // Position where the version line begins in the current match
+}
+impl Matcher for WarcMatcher {
+    fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult {
+        match self {
+            WarcMatcher::Header(matcher) => matcher.sequel(el_buf, pos),
+            WarcMatcher::Payload(remaining) => {
+                *remaining = remaining.saturating_sub(1);
+                if *remaining == 0 {
+                    MatchResult::Match(1, 0) //The last byte is a part of the payload
+                } else {
+                    MatchResult::Mismatch
+                }
+            }
+            WarcMatcher::NextRecord(start) => {
+                if pos == 0 {
+                    *start = 0;
+                }
+                let idx = pos - *start;
+                if el_buf == VERSION_LINE[idx] {
+                    if idx + 1 == VERSION_LINE.len() {
+                        // The end of line is the separator, `WARC/` goes to the next part
+                        MatchResult::Match(*start, VERSION_LINE.len() - 1)
+                    } else {
+                        MatchResult::NeedNext
+                    }
+                } else if el_buf == b'\n' {
+                    // The version line can begin again here
+                    *start = pos;
+                    MatchResult::NeedNext
+                } else {
+                    MatchResult::Mismatch
+                }
+            }
+        }
+    }
+}
+
+///
+/// Reader of WARC archives, see `next_record`
+///
+/// The payload of the current record is read with the `Read` implementation.
+/// The limit of the options (see `ReaderOptions::set_limit_record`) caps the size of a header block
+/// (64 KiB by default), the payloads are not kept in memory.
+pub struct WarcReader<'a> {
+    reader: BufReadSplitter<'a, WarcMatcher>,
+    position: Position,
+    first_read: bool,
+    payload_remain: u64, // Bytes of the payload not read yet
+    index: usize,        // Index of the next record
+    offset: u64,         // Position in the stream of the current record
+    limit_header: usize,
+}
+impl<'a> WarcReader<'a> {
+    pub fn new(reader: &'a mut dyn std::io::Read, options: ReaderOptions) -> Self {
+        Self {
+            reader: BufReadSplitter::new(reader, header_matcher(), options.splitter_options()),
+            position: Position::Header,
+            first_read: true,
+            payload_remain: 0,
+            index: 0,
+            offset: 0,
+            limit_header: options.limit_record.unwrap_or(MAX_HEADER_SZ),
+        }
+    }
+    ///
+    /// Go to the next record, skipping the payload not read of the current one
+    /// A record whose trailer is not `\r\n\r\n` is returned as a `RecordError::Malformed` once its payload
+    /// is skipped, and after an invalid record, the next record is searched at the next line beginning
+    /// with `WARC/`.
+    pub fn next_record(&mut self) -> std::result::Result<Option<WarcRecord>, RecordError> {
+        if self.first_read {
+            self.first_read = false;
+            if !self.next_part()? {
+                return Ok(None);
+            }
+        } else {
+            if self.position == Position::Payload {
+                self.position = Position::Trailer;
+                if !self.next_part()? {
+                    return Ok(None);
+                }
+                self.reader.matcher(WarcMatcher::NextRecord(0));
+            }
+            let has_next = match self.position {
+                Position::Trailer => self.skip_trailer()?,
+                Position::Invalid => {
+                    let has_next = self.next_part()?;
+                    self.reader.matcher(header_matcher());
+                    has_next
+                }
+                _ => true,
+            };
+            if !has_next {
+                return Ok(None);
+            }
+        }
+        self.position = Position::Header;
+
+        let index = self.index;
+        let offset = self.reader.part_offset();
+        self.offset = offset;
+        let malformed = |reason| RecordError::Malformed {
+            index,
+            offset,
+            reason,
+        };
+
+        let mut header = Vec::new();
+        self.reader
+            .set_limit_read(Some(self.limit_header.saturating_add(1)));
+        let res = self.reader.read_to_end(&mut header);
+        self.reader.set_limit_read(None);
+        res.map_err(|err| RecordError::Read(err.into()))?;
+
+        if !self.next_part()? {
+            if header.is_empty() {
+                return Ok(None); // Nothing after the last record
+            }
+            self.index += 1;
+            return Err(RecordError::Truncated { index, offset });
+        }
+        self.index += 1;
+
+        // The size of the payload is unknown until the header is parsed
+        self.position = Position::Invalid;
+        self.reader.matcher(WarcMatcher::NextRecord(0));
+        if header.len() > self.limit_header {
+            return Err(malformed("header block too large"));
+        }
+        let header = String::from_utf8(header).map_err(|_| malformed("invalid UTF-8 header"))?;
+        let record = parse_header(&header, offset).map_err(malformed)?;
+
+        self.payload_remain = record.content_length;
+        if record.content_length > 0 {
+            self.position = Position::Payload;
+            self.reader
+                .matcher(WarcMatcher::Payload(record.content_length));
+        } else {
+            self.position = Position::Trailer;
+        }
+        Ok(Some(record))
+    }
+
+    // ====== PRIVATE FUNCTIONS ====== //
+
+    ///
+    /// Check the trailer of the current record and go to the next record, return false at the end of the stream
+    /// The part of the trailer ends before the next version line, so with a valid trailer it's `\r\n\r`
+    /// (the last end of line is the separator), or `\r\n\r\n` at the end of the stream.
+    fn skip_trailer(&mut self) -> std::result::Result<bool, RecordError> {
+        let trailer = self
+            .reader
+            .peek(END_OF_HEADER.len() + 1)
+            .map_err(RecordError::Read)?
+            .to_vec();
+        let has_next = self.next_part()?;
+        self.reader.matcher(header_matcher());
+        self.position = Position::Header;
+
+        let expected = if has_next {
+            &END_OF_HEADER[..END_OF_HEADER.len() - 1]
+        } else {
+            END_OF_HEADER
+        };
+        if trailer != expected {
+            return Err(RecordError::Malformed {
+                index: self.index - 1,
+                offset: self.offset,
+                reason: "invalid record trailer",
+            });
+        }
+        Ok(has_next)
+    }
+
+    ///
+    /// Go to the next part, return false at the end of the stream
+    fn next_part(&mut self) -> std::result::Result<bool, RecordError> {
+        self.reader.next().map_err(RecordError::Read)
+    }
+}
+///
+/// Read implementation, to read the payload of the current record
+impl<'a> Read for WarcReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.position != Position::Payload {
+            return Ok(0);
+        }
+        let sz_read = self.reader.read(buf)?;
+        if sz_read == 0 && self.payload_remain > 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "Truncated WARC payload",
+            ));
+        }
+        self.payload_remain -= sz_read as u64;
+        Ok(sz_read)
+    }
+}
+
+fn header_matcher() -> WarcMatcher {
+    WarcMatcher::Header(SimpleMatcher::new(END_OF_HEADER))
+}
+
+///
+/// Parse a header block (version line followed by the fields)
+fn parse_header(header: &str, offset: u64) -> std::result::Result<WarcRecord, &'static str> {
+    let mut lines = header.lines();
+    let version = lines.next().unwrap_or_default();
+    if !version.starts_with("WARC/") {
+        return Err("missing WARC version line");
+    }
+
+    let mut fields: Vec<(String, String)> = Vec::new();
+    for line in lines {
+        if line.starts_with([' ', '\t']) {
+            // Continuation of the previous field
+            let (_, value) = fields.last_mut().ok_or("invalid header field")?;
+            value.push(' ');
+            value.push_str(line.trim());
+        } else {
+            let (name, value) = line.split_once(':').ok_or("invalid header field")?;
+            fields.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    let mut record = WarcRecord {
+        version: version.to_string(),
+        fields,
+        content_length: 0,
+        offset,
+    };
+    record.content_length = record
+        .get("Content-Length")
+        .ok_or("missing Content-Length")?
+        .parse()
+        .map_err(|_| "invalid Content-Length")?;
+    Ok(record)
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests_adaptive_extend {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, MatchResult, Matcher, Options};
+
+    ///
+    /// A block between brackets is the separator, so all the block is read in the extend buffer
+    struct BlockMatcher;
+    impl Matcher for BlockMatcher {
+        fn sequel(&mut self, el_buf: u8, pos: usize) -> MatchResult {
+            if pos == 0 && el_buf != b'[' {
+                MatchResult::Mismatch
+            } else if pos > 0 && el_buf == b']' {
+                MatchResult::Match(0, 0)
+            } else {
+                MatchResult::NeedNext
+            }
+        }
+    }
+
+    ///
+    /// Count the reads of the stream
+    struct CountingReader<'a> {
+        input: &'a [u8],
+        nb_read: usize,
+    }
+    impl Read for CountingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.nb_read += 1;
+            self.input.read(buf)
+        }
+    }
+
+    fn split(input: &[u8], sz_buf: usize, options: Options) -> (Vec<String>, usize) {
+        let mut input_reader = CountingReader { input, nb_read: 0 };
+        let mut parts = Vec::new();
+        {
+            let mut reader = BufReadSplitter::new(&mut input_reader, BlockMatcher, options);
+            let mut buf = vec![0u8; sz_buf];
+            while reader.next().unwrap() {
+                let mut part = Vec::new();
+                loop {
+                    let sz = reader.read(&mut buf).unwrap();
+                    if sz == 0 {
+                        break;
+                    }
+                    part.extend_from_slice(&buf[..sz]);
+                }
+                parts.push(String::from_utf8(part).unwrap());
+            }
+        }
+        (parts, input_reader.nb_read)
+    }
+
+    fn input() -> Vec<u8> {
+        let mut input = b"first[".to_vec();
+        input.extend(std::iter::repeat_n(b'x', 10_000));
+        input.extend_from_slice(b"]second[xx]third");
+        input
+    }
+
+    #[test]
+    fn test_same_parts() {
+        let input = input();
+        for sz in 1..20 {
+            for max_sz in [None, Some(1), Some(2), Some(100), Some(100_000)] {
+                let (parts, _) = split(
+                    &input,
+                    sz,
+                    Options::default()
+                        .set_reserve_sz_to_match(2)
+                        .set_extend_buffer_additionnal_sz(1)
+                        .set_adaptive_extend_buffer_max_sz(max_sz)
+                        .clone(),
+                );
+                assert_eq!(
+                    parts,
+                    vec!["first", "second", "third"],
+                    "Case buf sz={sz} max sz={max_sz:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_presets() {
+        let input = input();
+        for sz in [1, 7, 4096, 100_000] {
+            for options in [Options::for_files(), Options::for_sockets()] {
+                let (parts, _) = split(&input, sz, options);
+                assert_eq!(parts, vec!["first", "second", "third"], "Case buf sz={sz}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_less_reads() {
+        let input = input();
+        let mut options = Options::default();
+        options
+            .set_reserve_sz_to_match(2)
+            .set_extend_buffer_additionnal_sz(5);
+        let (_, nb_read_constant) = split(&input, 10, options.clone());
+        options.set_adaptive_extend_buffer_max_sz(Some(4096));
+        let (_, nb_read_adaptive) = split(&input, 10, options);
+        assert!(nb_read_constant > 2000, "{nb_read_constant} reads");
+        assert!(nb_read_adaptive < 50, "{nb_read_adaptive} reads");
+    }
+}
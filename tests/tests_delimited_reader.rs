@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests_delimited_reader {
+    use buf_read_splitter::{
+        AllEndOfLineMatcher, DelimitedReader, ReaderOptions, RecordError, SimpleMatcher,
+    };
+
+    fn options(sz_ext: usize) -> ReaderOptions {
+        ReaderOptions::default()
+            .set_reserve_sz_to_match(1)
+            .set_extend_buffer_additionnal_sz(sz_ext)
+            .clone()
+    }
+
+    #[test]
+    fn test_records() {
+        let input = "a<FS>bb<FS><RS><RS>c<RS><FS>d<FS><RS>";
+        for sz_ext in 1..20 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = DelimitedReader::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<RS>"),
+                SimpleMatcher::new(b"<FS>"),
+                options(sz_ext),
+            );
+            let mut records = Vec::new();
+            while let Some(fields) = reader.next_record().unwrap() {
+                records.push(
+                    fields
+                        .iter()
+                        .map(|f| String::from_utf8_lossy(f).to_string())
+                        .collect::<Vec<_>>(),
+                );
+            }
+            assert_eq!(
+                records,
+                vec![vec!["a", "bb", ""], vec!["c"], vec!["", "d", ""]],
+                "Case ext sz={sz_ext}"
+            );
+            assert!(reader.header().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_header() {
+        let input = "name\tage\r\n\r\nbob\t42\nalice\t7\n";
+        for sz_ext in 1..10 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = DelimitedReader::new(
+                &mut input_reader,
+                AllEndOfLineMatcher::new(),
+                SimpleMatcher::new(b"\t"),
+                options(sz_ext),
+            );
+            reader.set_has_header(true);
+            assert_eq!(
+                reader.header().unwrap(),
+                Some(&[b"name".to_vec(), b"age".to_vec()][..]),
+                "Case ext sz={sz_ext}"
+            );
+            let mut text = String::new();
+            while reader
+                .visit_record(|i, field| {
+                    text.push_str(&format!("{i}={} ", String::from_utf8_lossy(field)))
+                })
+                .unwrap()
+            {
+                text.push('|');
+            }
+            assert_eq!(text, "0=bob 1=42 |0=alice 1=7 |", "Case ext sz={sz_ext}");
+        }
+    }
+
+    #[test]
+    fn test_header_read_with_first_record() {
+        let input = "h1,h2\nv1,v2";
+        let mut input_reader = input.as_bytes();
+        let mut reader = DelimitedReader::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"\n"),
+            SimpleMatcher::new(b","),
+            ReaderOptions::default(),
+        );
+        reader.set_has_header(true);
+        let record: Vec<Vec<u8>> = reader
+            .next_record()
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|f| f.into_owned())
+            .collect();
+        assert_eq!(record, vec![b"v1".to_vec(), b"v2".to_vec()]);
+        assert_eq!(
+            reader.header().unwrap(),
+            Some(&[b"h1".to_vec(), b"h2".to_vec()][..])
+        );
+        assert!(reader.next_record().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_max_fields() {
+        let input = "a,b\na,b,c,d\nc,d";
+        for sz_ext in 1..10 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = DelimitedReader::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"\n"),
+                SimpleMatcher::new(b","),
+                options(sz_ext),
+            );
+            reader.set_max_fields(Some(2));
+            assert_eq!(reader.next_record().unwrap().unwrap().len(), 2);
+            match reader.next_record() {
+                Err(RecordError::Malformed { index, offset, .. }) => {
+                    assert_eq!((index, offset), (1, 4), "Case ext sz={sz_ext}")
+                }
+                _ => panic!("Case ext sz={sz_ext} : expected an error"),
+            }
+            let fields = reader.next_record().unwrap().unwrap();
+            assert_eq!(fields, vec![&b"c"[..], &b"d"[..]], "Case ext sz={sz_ext}");
+            assert!(reader.next_record().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_limit_record() {
+        let input = "a<FS>b<RS>a<FS>bc<RS><RS>c<RS>";
+        for sz_ext in 1..10 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = DelimitedReader::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<RS>"),
+                SimpleMatcher::new(b"<FS>"),
+                options(sz_ext).set_limit_record(Some(6)).clone(),
+            );
+            assert_eq!(
+                reader.next_record().unwrap().unwrap(),
+                vec![b"a".as_slice(), b"b"],
+                "Case 1 sz_ext={sz_ext}"
+            );
+            match reader.next_record() {
+                Err(RecordError::Malformed { index, offset, .. }) => {
+                    assert_eq!(index, 1, "Case 2a sz_ext={sz_ext}");
+                    assert_eq!(offset, 10, "Case 2b sz_ext={sz_ext}");
+                }
+                other => panic!("Unexpected result : {other:?}"),
+            }
+            assert_eq!(
+                reader.next_record().unwrap().unwrap(),
+                vec![b"c".as_slice()],
+                "Case 3 sz_ext={sz_ext}"
+            );
+            assert!(reader.next_record().unwrap().is_none(), "Case 4");
+        }
+    }
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests_fixed_size {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Options, SimpleMatcher};
+
+    fn read_all(input: &str, options: Options, sz_buf: usize) -> Vec<(u64, String)> {
+        let mut input_reader = input.as_bytes();
+        let mut reader =
+            BufReadSplitter::new(&mut input_reader, SimpleMatcher::new(b"<SEP>"), options);
+        let mut buf = vec![0u8; sz_buf];
+        let mut parts = Vec::new();
+        while reader.next().unwrap() {
+            let mut text = String::new();
+            let mut sz;
+            while {
+                sz = reader.read(&mut buf).unwrap();
+                sz > 0
+            } {
+                text.push_str(&String::from_utf8_lossy(&buf[..sz]));
+            }
+            parts.push((reader.part_offset(), text));
+        }
+        parts
+    }
+
+    #[test]
+    fn test_exact_multiple() {
+        for sz_buf in 1..20 {
+            let parts = read_all(
+                "abcdefghi",
+                Options::default().set_fixed_size(Some(3)).clone(),
+                sz_buf,
+            );
+            assert_eq!(
+                parts,
+                vec![
+                    (0, "abc".to_string()),
+                    (3, "def".to_string()),
+                    (6, "ghi".to_string())
+                ],
+                "Case buf sz={sz_buf}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_last() {
+        for sz_buf in 1..20 {
+            // The separator is ignored in fixed size mode
+            let parts = read_all(
+                "ab<SEP>cdefg",
+                Options::default().set_fixed_size(Some(4)).clone(),
+                sz_buf,
+            );
+            assert_eq!(
+                parts,
+                vec![
+                    (0, "ab<S".to_string()),
+                    (4, "EP>c".to_string()),
+                    (8, "defg".to_string())
+                ],
+                "Case 1 buf sz={sz_buf}"
+            );
+
+            let parts = read_all(
+                "abcdefgh",
+                Options::default().set_fixed_size(Some(5)).clone(),
+                sz_buf,
+            );
+            assert_eq!(
+                parts,
+                vec![(0, "abcde".to_string()), (5, "fgh".to_string())],
+                "Case 2 buf sz={sz_buf}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let parts = read_all("", Options::default().set_fixed_size(Some(3)).clone(), 10);
+        assert!(parts.is_empty());
+    }
+
+    #[test]
+    fn test_refuse_partial() {
+        for sz_buf in 1..20 {
+            let input = "abcdefgh";
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<SEP>"),
+                Options::default()
+                    .set_fixed_size(Some(3))
+                    .set_fixed_size_partial(false)
+                    .clone(),
+            );
+            let mut buf = vec![0u8; sz_buf];
+            let mut parts = Vec::new();
+            let mut err = None;
+            while reader.next().unwrap() {
+                let mut part = Vec::new();
+                loop {
+                    match reader.read(&mut buf) {
+                        Ok(0) => break,
+                        Ok(sz) => part.extend_from_slice(&buf[..sz]),
+                        Err(e) => {
+                            err = Some(e);
+                            break;
+                        }
+                    }
+                }
+                parts.push(part);
+                if err.is_some() {
+                    break;
+                }
+            }
+            assert_eq!(
+                parts,
+                vec![b"abc".to_vec(), b"def".to_vec(), b"gh".to_vec()],
+                "Case buf sz={sz_buf}"
+            );
+            assert_eq!(
+                err.map(|e| e.kind()),
+                Some(std::io::ErrorKind::UnexpectedEof),
+                "Case buf sz={sz_buf}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_refuse_partial_exact_multiple() {
+        let parts = read_all(
+            "abcdef",
+            Options::default()
+                .set_fixed_size(Some(3))
+                .set_fixed_size_partial(false)
+                .clone(),
+            2,
+        );
+        assert_eq!(parts, vec![(0, "abc".to_string()), (3, "def".to_string())]);
+    }
+
+    #[test]
+    fn test_next_part_skip() {
+        for sz_buf in 1..10 {
+            // Parts not read, or partially read, are skipped
+            let input = "0123456789ABCDEF";
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<SEP>"),
+                Options::default().set_fixed_size(Some(5)).clone(),
+            );
+            let mut buf = vec![0u8; sz_buf];
+            let mut firsts = Vec::new();
+            while reader.next().unwrap() {
+                let sz = reader.read(&mut buf[..1]).unwrap();
+                firsts.push(buf[..sz].to_vec());
+            }
+            assert_eq!(
+                firsts,
+                vec![b"0".to_vec(), b"5".to_vec(), b"A".to_vec(), b"F".to_vec()],
+                "Case buf sz={sz_buf}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_limit_read() {
+        for sz_buf in 1..20 {
+            let parts = read_all(
+                "abcdefghij",
+                Options::default()
+                    .set_fixed_size(Some(4))
+                    .set_limit_read(Some(3))
+                    .clone(),
+                sz_buf,
+            );
+            assert_eq!(
+                parts,
+                vec![
+                    (0, "abc".to_string()),
+                    (4, "efg".to_string()),
+                    (8, "ij".to_string())
+                ],
+                "Case buf sz={sz_buf}"
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests_into_parts {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Options, SimpleMatcher};
+
+    const INPUT: &[u8] = b"GET / HTTP/1.1\r\nUpgrade: x\r\n\r\n\x00\x01BODY\r\n\r\nnot a header";
+
+    fn read_part(reader: &mut BufReadSplitter<SimpleMatcher>, buf: &mut [u8]) -> String {
+        let mut part = String::new();
+        loop {
+            let sz = reader.read(buf).unwrap();
+            if sz == 0 {
+                break;
+            }
+            part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+        }
+        part
+    }
+
+    #[test]
+    fn test_remaining_reader() {
+        for sz_ext in 1..8 {
+            for sz in 1..40 {
+                let mut input_reader = INPUT;
+                let mut reader = BufReadSplitter::new(
+                    &mut input_reader,
+                    SimpleMatcher::new(b"\r\n"),
+                    Options::default()
+                        .set_reserve_sz_to_match(2)
+                        .set_extend_buffer_additionnal_sz(sz_ext)
+                        .clone(),
+                );
+                let mut buf = vec![0u8; sz];
+                let mut headers = Vec::new();
+                while reader.next().unwrap() {
+                    let header = read_part(&mut reader, &mut buf);
+                    if header.is_empty() {
+                        break;
+                    }
+                    headers.push(header);
+                }
+                assert_eq!(
+                    headers,
+                    vec!["GET / HTTP/1.1", "Upgrade: x"],
+                    "Case buf sz={sz} ext sz={sz_ext}"
+                );
+
+                let mut body = Vec::new();
+                reader
+                    .into_remaining_reader()
+                    .read_to_end(&mut body)
+                    .unwrap();
+                assert_eq!(
+                    body, b"\x00\x01BODY\r\n\r\nnot a header",
+                    "Case buf sz={sz} ext sz={sz_ext}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_parts() {
+        for sz in 1..20 {
+            let mut input_reader = INPUT;
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"\r\n"),
+                Options::default(),
+            );
+            let mut buf = vec![0u8; sz];
+            assert!(reader.next().unwrap());
+            assert_eq!(read_part(&mut reader, &mut buf), "GET / HTTP/1.1");
+
+            let (stream, mut remaining) = reader.into_parts();
+            stream.read_to_end(&mut remaining).unwrap();
+            assert_eq!(remaining, &INPUT[16..], "Case buf sz={sz}");
+        }
+    }
+
+    #[test]
+    fn test_not_started() {
+        let mut input_reader = INPUT;
+        let reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"\r\n"),
+            Options::default(),
+        );
+        let mut all = Vec::new();
+        reader
+            .into_remaining_reader()
+            .read_to_end(&mut all)
+            .unwrap();
+        assert_eq!(all, INPUT);
+    }
+
+    #[test]
+    fn test_trimmed() {
+        // The bytes held to be trimmed at the end of the part are given back
+        let input = b"first  \t<SEP>  x";
+        let mut input_reader = &input[..];
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"<SEP>"),
+            Options::default().set_trim(true).clone(),
+        );
+        let mut buf = vec![0u8; 8];
+        assert!(reader.next().unwrap());
+        assert_eq!(reader.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf[..5], b"first");
+
+        let mut rest = Vec::new();
+        reader
+            .into_remaining_reader()
+            .read_to_end(&mut rest)
+            .unwrap();
+        assert_eq!(rest, b"  \t<SEP>  x");
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "serde")]
+#[cfg(test)]
+mod tests_json_lines {
+    use buf_read_splitter::{JsonLines, ReaderOptions, RecordError};
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Event {
+        id: u32,
+        name: String,
+    }
+
+    #[test]
+    fn test_records() {
+        let input = "{\"id\":1,\"name\":\"one\"}\n\n  \r\n{\"id\":2,\"name\":\"two\"}\r\n{\"id\":3,\"name\":\"three\"}".to_string();
+        let mut input_reader = input.as_bytes();
+        let records: Vec<Event> = JsonLines::new(&mut input_reader, ReaderOptions::default())
+            .map(|res| res.unwrap())
+            .collect();
+
+        assert_eq!(records.len(), 3, "Case 1");
+        assert_eq!(
+            records[0],
+            Event {
+                id: 1,
+                name: "one".to_string()
+            },
+            "Case 2"
+        );
+        assert_eq!(
+            records[1],
+            Event {
+                id: 2,
+                name: "two".to_string()
+            },
+            "Case 3"
+        );
+        assert_eq!(
+            records[2],
+            Event {
+                id: 3,
+                name: "three".to_string()
+            },
+            "Case 4"
+        );
+    }
+
+    #[test]
+    fn test_continue_after_error() {
+        let input =
+            "{\"id\":1,\"name\":\"one\"}\n{\"id\":\"bad\"}\n\n{\"id\":3,\"name\":\"three\"}\n"
+                .to_string();
+        let mut input_reader = input.as_bytes();
+        let mut lines = JsonLines::<Event>::new(&mut input_reader, ReaderOptions::default());
+
+        assert_eq!(lines.next().unwrap().unwrap().id, 1, "Case 1");
+        match lines.next().unwrap() {
+            Err(RecordError::Deserialize { index, offset, .. }) => {
+                assert_eq!(index, 1, "Case 2a");
+                assert_eq!(offset, 22, "Case 2b");
+            }
+            other => panic!("Unexpected result : {other:?}"),
+        }
+        assert_eq!(lines.next().unwrap().unwrap().id, 3, "Case 3");
+        assert!(lines.next().is_none(), "Case 4");
+    }
+
+    #[test]
+    fn test_empty() {
+        let input = "\n\r\n  \n".to_string();
+        let mut input_reader = input.as_bytes();
+        let mut lines = JsonLines::<Event>::new(&mut input_reader, ReaderOptions::default());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_limit_record() {
+        let input = "{\"id\":1,\"name\":\"one\"}\n{\"id\":2,\"name\":\"two, too long\"}\n{\"id\":3,\"name\":\"three\"}\n".to_string();
+        let mut input_reader = input.as_bytes();
+        let mut lines = JsonLines::<Event>::new(
+            &mut input_reader,
+            ReaderOptions::default().set_limit_record(Some(24)).clone(),
+        );
+
+        assert_eq!(lines.next().unwrap().unwrap().id, 1, "Case 1");
+        match lines.next().unwrap() {
+            Err(RecordError::Malformed { index, offset, .. }) => {
+                assert_eq!(index, 1, "Case 2a");
+                assert_eq!(offset, 22, "Case 2b");
+            }
+            other => panic!("Unexpected result : {other:?}"),
+        }
+        assert_eq!(lines.next().unwrap().unwrap().id, 3, "Case 3");
+        assert!(lines.next().is_none(), "Case 4");
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests_json_seq {
+    use std::io::{ErrorKind, Read};
+
+    use buf_read_splitter::{JsonSeq, ReaderOptions, RecordError};
+
+    ///
+    /// Return a `WouldBlock` error before each chunk of `sz_chunk` bytes
+    struct BlockingReader<'a> {
+        input: &'a [u8],
+        sz_chunk: usize,
+        blocked: bool,
+    }
+    impl Read for BlockingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.blocked = !self.blocked;
+            if self.blocked && !self.input.is_empty() {
+                return Err(ErrorKind::WouldBlock.into());
+            }
+            let sz = self.sz_chunk.min(buf.len());
+            self.input.read(&mut buf[..sz])
+        }
+    }
+
+    #[test]
+    fn test_records() {
+        let input = "\x1e{\"a\":1}\n\x1e\x1e[1,2]\n\x1e\"text\"\n".to_string();
+        let mut input_reader = input.as_bytes();
+        let records: Vec<Vec<u8>> = JsonSeq::new(&mut input_reader, ReaderOptions::default())
+            .map(|res| res.unwrap())
+            .collect();
+
+        assert_eq!(records.len(), 3, "Case 1");
+        assert_eq!(&records[0], b"{\"a\":1}", "Case 2");
+        assert_eq!(&records[1], b"[1,2]", "Case 3");
+        assert_eq!(&records[2], b"\"text\"", "Case 4");
+    }
+
+    #[test]
+    fn test_truncated() {
+        let input = "\x1e{\"a\":1}\n\x1e12\x1e[1]\n\x1e{\"b\"".to_string();
+        let mut input_reader = input.as_bytes();
+        let mut seq = JsonSeq::new(&mut input_reader, ReaderOptions::default());
+
+        assert_eq!(seq.next().unwrap().unwrap(), b"{\"a\":1}", "Case 1");
+        match seq.next().unwrap() {
+            Err(RecordError::Truncated { index, offset }) => {
+                assert_eq!(index, 1, "Case 2a");
+                assert_eq!(offset, 10, "Case 2b");
+            }
+            other => panic!("Unexpected result : {other:?}"),
+        }
+        assert_eq!(seq.next().unwrap().unwrap(), b"[1]", "Case 3");
+        assert!(
+            matches!(
+                seq.next(),
+                Some(Err(RecordError::Truncated { index: 3, .. }))
+            ),
+            "Case 4"
+        );
+        assert!(seq.next().is_none(), "Case 5");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_values() {
+        let input = "\x1e[1,2]\n\x1e[3\n\x1e[4]\n".to_string();
+        let mut input_reader = input.as_bytes();
+        let mut seq = JsonSeq::new(&mut input_reader, ReaderOptions::default());
+
+        let value: Vec<u32> = seq.next_value().unwrap().unwrap();
+        assert_eq!(value, vec![1, 2], "Case 1");
+        let res = seq.next_value::<Vec<u32>>().unwrap();
+        assert!(
+            matches!(res, Err(RecordError::Deserialize { index: 1, .. })),
+            "Case 2"
+        );
+        let value: Vec<u32> = seq.next_value().unwrap().unwrap();
+        assert_eq!(value, vec![4], "Case 3");
+        assert!(seq.next_value::<Vec<u32>>().is_none(), "Case 4");
+    }
+
+    #[test]
+    fn test_limit_record() {
+        let input = "\x1e[1,2,3]\n\x1e[1,2,3,4]\n\x1e[4]\n".to_string();
+        let mut input_reader = input.as_bytes();
+        let mut seq = JsonSeq::new(
+            &mut input_reader,
+            ReaderOptions::default().set_limit_record(Some(8)).clone(),
+        );
+
+        assert_eq!(seq.next().unwrap().unwrap(), b"[1,2,3]", "Case 1");
+        match seq.next().unwrap() {
+            Err(RecordError::Malformed { index, offset, .. }) => {
+                assert_eq!(index, 1, "Case 2a");
+                assert_eq!(offset, 10, "Case 2b");
+            }
+            other => panic!("Unexpected result : {other:?}"),
+        }
+        assert_eq!(seq.next().unwrap().unwrap(), b"[4]", "Case 3");
+        assert!(seq.next().is_none(), "Case 4");
+    }
+
+    #[test]
+    fn test_text_before_first_rs() {
+        for (input, offset, expected) in [
+            ("garbage\n\x1e{\"a\":1}\n", 0, "{\"a\":1}"),
+            ("{\"not\":\"rs\"}\n\x1e1\n", 0, "1"),
+        ] {
+            let mut input_reader = input.as_bytes();
+            let mut seq = JsonSeq::new(&mut input_reader, ReaderOptions::default());
+            match seq.next().unwrap() {
+                Err(RecordError::Malformed {
+                    index, offset: o, ..
+                }) => {
+                    assert_eq!(index, 0, "Case {input:?}");
+                    assert_eq!(o, offset, "Case {input:?}");
+                }
+                other => panic!("Unexpected result : {other:?}"),
+            }
+            assert_eq!(
+                seq.next().unwrap().unwrap(),
+                expected.as_bytes(),
+                "Case {input:?}"
+            );
+            assert!(seq.next().is_none(), "Case {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_would_block() {
+        let input = "\x1e[1,2,3,4]\n\x1e\x1e[5]\n";
+        for sz_chunk in 1..8 {
+            let mut input_reader = BlockingReader {
+                input: input.as_bytes(),
+                sz_chunk,
+                blocked: false,
+            };
+            let seq = JsonSeq::new(&mut input_reader, ReaderOptions::default());
+            let mut records = Vec::new();
+            for res in seq {
+                match res {
+                    Ok(record) => records.push(String::from_utf8(record).unwrap()),
+                    Err(RecordError::Read(err)) => assert_eq!(
+                        err.downcast_ref::<std::io::Error>().unwrap().kind(),
+                        ErrorKind::WouldBlock,
+                        "Case sz_chunk={sz_chunk}"
+                    ),
+                    Err(err) => panic!("Case sz_chunk={sz_chunk} : {err}"),
+                }
+            }
+            assert_eq!(
+                records,
+                vec!["[1,2,3,4]", "[5]"],
+                "Case sz_chunk={sz_chunk}"
+            );
+        }
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests_json_value_matcher {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, JsonValueMatcher, Options};
+
+    fn split(input: &str, sz_buf: usize, matcher: JsonValueMatcher) -> Vec<String> {
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            matcher,
+            Options::default()
+                .set_reserve_sz_to_match(2)
+                .set_extend_buffer_additionnal_sz(1)
+                .clone(),
+        );
+        let mut parts = Vec::new();
+        let mut buf = vec![0u8; sz_buf];
+        while reader.next().unwrap() {
+            let mut part = String::new();
+            loop {
+                let sz = reader.read(&mut buf).unwrap();
+                if sz == 0 {
+                    break;
+                }
+                part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+            }
+            parts.push(part);
+        }
+        parts
+    }
+
+    #[test]
+    fn test_concatenated_values() {
+        let input = r#"{"a":{"b":[1,{"c":"}"}]}}[1,2,[3]]{"s":"\"{"}"#;
+        for sz in 1..60 {
+            let parts = split(input, sz, JsonValueMatcher::new());
+            assert_eq!(
+                parts,
+                vec![
+                    r#"{"a":{"b":[1,{"c":"}"}]}}"#,
+                    "[1,2,[3]]",
+                    r#"{"s":"\"{"}"#,
+                    "",
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_scalars_and_whitespace() {
+        let input = "12 \"a]\" true\n{}null";
+        for sz in 1..30 {
+            let parts = split(input, sz, JsonValueMatcher::new());
+            assert_eq!(
+                parts,
+                vec!["12", " \"a]\"", " true", "\n{}", "null"],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let input = "[[[1]]][[2]] [[[\"]\"]]]";
+        for sz in 1..30 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                JsonValueMatcher::with_max_depth(2),
+                Options::default()
+                    .set_reserve_sz_to_match(2)
+                    .set_extend_buffer_additionnal_sz(1)
+                    .clone(),
+            );
+            let mut parts = Vec::new();
+            let mut buf = vec![0u8; sz];
+            while reader.next().unwrap() {
+                let mut part = String::new();
+                loop {
+                    let sz = reader.read(&mut buf).unwrap();
+                    if sz == 0 {
+                        break;
+                    }
+                    part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+                }
+                parts.push((part, reader.get_matcher().too_deep()));
+            }
+            assert_eq!(
+                parts,
+                vec![
+                    ("[[[1]]]".to_string(), true),
+                    ("[[2]]".to_string(), false),
+                    (" [[[\"]\"]]]".to_string(), true),
+                    ("".to_string(), false), // No value
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests_matcher_fn {
+    use std::io::Read;
+
+    use buf_read_splitter::{
+        AllEndOfLineMatcher, BufReadSplitter, JsonValueMatcher, Matcher, Options, PartInfo,
+        SimpleMatcher,
+    };
+
+    fn read_parts<T: Matcher>(reader: &mut BufReadSplitter<T>, sz_buf: usize) -> Vec<String> {
+        let mut parts = Vec::new();
+        let mut buf = vec![0u8; sz_buf];
+        while reader.next().unwrap() {
+            let mut part = String::new();
+            loop {
+                let sz = reader.read(&mut buf).unwrap();
+                if sz == 0 {
+                    break;
+                }
+                part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+            }
+            parts.push(part);
+        }
+        parts
+    }
+
+    #[test]
+    fn test_header_body() {
+        let input = "H1\r\nA: 1\r\n\r\nline 1\nline 2<END>H2\r\n\r\nbody<END>";
+        for sz in 1..40 {
+            let mut infos = Vec::new();
+            let mut input_reader = input.as_bytes();
+            let mut reader: BufReadSplitter<Box<dyn Matcher>> = BufReadSplitter::new(
+                &mut input_reader,
+                Box::new(SimpleMatcher::new(b"\r\n\r\n")),
+                Options::default()
+                    .set_reserve_sz_to_match(2)
+                    .set_extend_buffer_additionnal_sz(1)
+                    .clone(),
+            );
+            reader.set_matcher_fn(|index, prev_part| {
+                infos.push((index, *prev_part));
+                if index % 2 == 0 {
+                    Box::new(SimpleMatcher::new(b"\r\n\r\n")) as Box<dyn Matcher>
+                } else {
+                    Box::new(SimpleMatcher::new(b"<END>"))
+                }
+            });
+            let parts = read_parts(&mut reader, sz);
+            drop(reader);
+            assert_eq!(
+                parts,
+                vec!["H1\r\nA: 1", "line 1\nline 2", "H2", "body", ""],
+                "Case buf sz={sz}"
+            );
+            assert_eq!(
+                infos,
+                vec![
+                    (1, PartInfo { offset: 0, len: 12 }),
+                    (
+                        2,
+                        PartInfo {
+                            offset: 12,
+                            len: 18
+                        }
+                    ),
+                    (3, PartInfo { offset: 30, len: 6 }),
+                    (4, PartInfo { offset: 36, len: 9 }),
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_different_kinds() {
+        // A line giving the number of JSON values which follow
+        let input = "2\n{\"a\":1}[2]1\n\"x\"";
+        for sz in 1..20 {
+            let mut input_reader = input.as_bytes();
+            let mut reader: BufReadSplitter<Box<dyn Matcher>> = BufReadSplitter::new(
+                &mut input_reader,
+                Box::new(AllEndOfLineMatcher::new()),
+                Options::default(),
+            );
+            let mut remaining = [2, 1].into_iter();
+            let mut values = 0;
+            reader.set_matcher_fn(move |_, _| {
+                if values > 0 {
+                    values -= 1;
+                    if values > 0 {
+                        return Box::new(JsonValueMatcher::new());
+                    }
+                    Box::new(AllEndOfLineMatcher::new())
+                } else {
+                    values = remaining.next().unwrap_or(0);
+                    Box::new(JsonValueMatcher::new())
+                }
+            });
+            let parts = read_parts(&mut reader, sz);
+            assert_eq!(
+                parts,
+                vec!["2", "{\"a\":1}", "[2]", "1", "\"x\"", ""],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests_mbox {
+    use std::io::Read;
+
+    use buf_read_splitter::{
+        BufReadSplitter, MboxMatcher, MboxSplitter, Options, ReaderOptions, RecordError,
+    };
+
+    const MAILBOX: &str = "From alice@example.com Mon Jan  1 00:00:00 2024\nSubject: one\n\nHello From here\n>From the start\n\n\nFrom bob@example.com Tue Jan  2 00:00:00 2024\nSubject: two\n\n>>From quoted\nFrom: not a separator\n\n";
+
+    #[test]
+    fn test_matcher() {
+        for sz in 1..40 {
+            let input = "preamble\nFrom a\nx\n\nFrom b\nFrom\nFrom c".to_string();
+            let mut input_reader = input.as_bytes();
+            let mut reader =
+                BufReadSplitter::new(&mut input_reader, MboxMatcher::new(), Options::default());
+            let mut parts = Vec::new();
+            let mut buf = vec![0u8; sz];
+            while reader.next().unwrap() {
+                let mut part = String::new();
+                loop {
+                    let sz_read = reader.read(&mut buf).unwrap();
+                    if sz_read == 0 {
+                        break;
+                    }
+                    part.push_str(&String::from_utf8_lossy(&buf[..sz_read]));
+                }
+                parts.push(part);
+            }
+            assert_eq!(
+                parts,
+                vec!["preamble", "From a\nx\n", "From b\nFrom", "From c"],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_at_start() {
+        let input = "From a\nx\nFrom b\ny".to_string();
+        let mut input_reader = input.as_bytes();
+        let mut reader =
+            BufReadSplitter::new(&mut input_reader, MboxMatcher::new(), Options::default());
+        let mut parts = Vec::new();
+        while reader.next().unwrap() {
+            let mut part = String::new();
+            reader.read_to_string(&mut part).unwrap();
+            parts.push(part);
+        }
+        assert_eq!(parts, vec!["", "From a\nx", "From b\ny"]);
+    }
+
+    #[test]
+    fn test_messages() {
+        let mut input_reader = MAILBOX.as_bytes();
+        let messages: Vec<_> = MboxSplitter::new(&mut input_reader, ReaderOptions::default())
+            .map(|res| res.unwrap())
+            .collect();
+
+        assert_eq!(messages.len(), 2, "Case 1");
+        assert_eq!(
+            messages[0].envelope, b"alice@example.com Mon Jan  1 00:00:00 2024",
+            "Case 2a"
+        );
+        assert_eq!(
+            messages[0].body, b"Subject: one\n\nHello From here\n>From the start\n\n",
+            "Case 2b"
+        );
+        assert_eq!(
+            messages[1].envelope, b"bob@example.com Tue Jan  2 00:00:00 2024",
+            "Case 3a"
+        );
+        assert_eq!(
+            messages[1].body, b"Subject: two\n\n>>From quoted\nFrom: not a separator\n\n",
+            "Case 3b"
+        );
+    }
+
+    #[test]
+    fn test_mboxrd() {
+        let mut input_reader = MAILBOX.as_bytes();
+        let mut splitter = MboxSplitter::new(&mut input_reader, ReaderOptions::default());
+        splitter.set_mboxrd(true);
+        let messages: Vec<_> = splitter.map(|res| res.unwrap()).collect();
+
+        assert_eq!(
+            messages[0].body, b"Subject: one\n\nHello From here\nFrom the start\n\n",
+            "Case 1"
+        );
+        assert_eq!(
+            messages[1].body, b"Subject: two\n\n>From quoted\nFrom: not a separator\n\n",
+            "Case 2"
+        );
+    }
+
+    #[test]
+    fn test_limit_record() {
+        let input = "From a\nshort\nFrom b\nthis one is too long\nFrom c\nshort\n".to_string();
+        let mut input_reader = input.as_bytes();
+        let mut splitter = MboxSplitter::new(
+            &mut input_reader,
+            ReaderOptions::default().set_limit_record(Some(13)).clone(),
+        );
+
+        assert_eq!(splitter.next().unwrap().unwrap().envelope, b"a", "Case 1");
+        match splitter.next().unwrap() {
+            Err(RecordError::Malformed { index, offset, .. }) => {
+                assert_eq!(index, 1, "Case 2a");
+                assert_eq!(offset, 13, "Case 2b");
+            }
+            other => panic!("Unexpected result : {other:?}"),
+        }
+        assert_eq!(splitter.next().unwrap().unwrap().body, b"short\n", "Case 3");
+        assert!(splitter.next().is_none(), "Case 4");
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests_next_part_exact {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Options, SimpleMatcher};
+
+    fn read_part(reader: &mut BufReadSplitter<SimpleMatcher>, buf: &mut [u8]) -> String {
+        let mut part = String::new();
+        loop {
+            let sz = reader.read(buf).unwrap();
+            if sz == 0 {
+                break;
+            }
+            part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+        }
+        part
+    }
+
+    #[test]
+    fn test_content_length() {
+        // The bodies contain the separator
+        let input = "len=6\r\n\r\na\r\n\r\nblen=0\r\n\r\nlen=13\r\n\r\n\r\n\r\nlen=1\r\n\r\n";
+        for sz_ext in 1..12 {
+            for sz in 1..30 {
+                let mut input_reader = input.as_bytes();
+                let mut reader = BufReadSplitter::new(
+                    &mut input_reader,
+                    SimpleMatcher::new(b"\r\n\r\n"),
+                    Options::default()
+                        .set_reserve_sz_to_match(2)
+                        .set_extend_buffer_additionnal_sz(sz_ext)
+                        .clone(),
+                );
+                let mut buf = vec![0u8; sz];
+                let mut messages = Vec::new();
+                while reader.next().unwrap() {
+                    let header = read_part(&mut reader, &mut buf);
+                    let len: u64 = header.trim_start_matches("len=").parse().unwrap();
+                    assert!(reader.next_part_exact(len).unwrap().is_some());
+                    let body = read_part(&mut reader, &mut buf);
+                    messages.push((reader.part_offset(), header, body));
+                }
+                assert_eq!(
+                    messages,
+                    vec![
+                        (9, "len=6".to_string(), "a\r\n\r\nb".to_string()),
+                        (24, "len=0".to_string(), "".to_string()),
+                        (
+                            34,
+                            "len=13".to_string(),
+                            "\r\n\r\nlen=1\r\n\r\n".to_string()
+                        ),
+                    ],
+                    "Case buf sz={sz} ext sz={sz_ext}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncated() {
+        for sz in 1..20 {
+            let input = "len=10\r\n\r\nabc";
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"\r\n\r\n"),
+                Options::default(),
+            );
+            let mut buf = vec![0u8; sz];
+            assert!(reader.next().unwrap());
+            assert_eq!(read_part(&mut reader, &mut buf), "len=10");
+            assert!(reader.next_part_exact(10).unwrap().is_some());
+            let mut body = Vec::new();
+            let err = reader.read_to_end(&mut body).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                std::io::ErrorKind::UnexpectedEof,
+                "Case buf sz={sz}"
+            );
+            assert_eq!(body, b"abc", "Case buf sz={sz}");
+        }
+    }
+
+    #[test]
+    fn test_end_of_stream() {
+        let input = "abc";
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"\r\n\r\n"),
+            Options::default(),
+        );
+        assert!(reader.next().unwrap());
+        assert!(reader.next_part_exact(3).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_skipped() {
+        for sz in 1..20 {
+            // The exact part is not read, it's skipped by the next part
+            let input = "2|xx|3|y|y|last";
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"|"),
+                Options::default(),
+            );
+            let mut buf = vec![0u8; sz];
+            let mut parts = Vec::new();
+            while reader.next().unwrap() {
+                let part = read_part(&mut reader, &mut buf);
+                if let Ok(len) = part.parse::<u64>() {
+                    reader.next_part_exact(len + 1).unwrap();
+                }
+                parts.push(part);
+            }
+            assert_eq!(parts, vec!["2", "3", "last"], "Case buf sz={sz}");
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests_nul_matcher {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, NulMatcher, Options};
+
+    fn split(input: &[u8], sz_buf: usize) -> Vec<Vec<u8>> {
+        let mut input_reader = input;
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            NulMatcher::new(),
+            Options::default()
+                .set_reserve_sz_to_match(2)
+                .set_extend_buffer_additionnal_sz(1)
+                .clone(),
+        );
+        let mut parts = Vec::new();
+        let mut buf = vec![0u8; sz_buf];
+        while reader.next().unwrap() {
+            let mut part = Vec::new();
+            loop {
+                let sz = reader.read(&mut buf).unwrap();
+                if sz == 0 {
+                    break;
+                }
+                part.extend_from_slice(&buf[..sz]);
+            }
+            parts.push(part);
+        }
+        parts
+    }
+
+    #[test]
+    fn test_print0() {
+        let input = b"./a\0./dir/b c\0\0./d\n\0";
+        for sz in 1..30 {
+            assert_eq!(
+                split(input, sz),
+                vec![
+                    b"./a".to_vec(),
+                    b"./dir/b c".to_vec(),
+                    b"".to_vec(),
+                    b"./d\n".to_vec(),
+                    b"".to_vec(),
+                ],
+                "Case 1 buf sz={sz}"
+            );
+            assert_eq!(
+                split(b"\0last", sz),
+                vec![b"".to_vec(), b"last".to_vec()],
+                "Case 2 buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_long_records() {
+        let mut input = Vec::new();
+        for i in 0..20 {
+            input.extend(std::iter::repeat_n(b'a' + i as u8, i * 37));
+            input.push(0);
+        }
+        for sz in [1, 7, 64, 1000] {
+            let parts = split(&input, sz);
+            assert_eq!(parts.len(), 21, "Case buf sz={sz}");
+            for (i, part) in parts.iter().take(20).enumerate() {
+                assert_eq!(part.len(), i * 37, "Case part {i} buf sz={sz}");
+                assert!(part.iter().all(|b| *b == b'a' + i as u8));
+            }
+        }
+    }
+}
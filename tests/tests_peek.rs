@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests_peek {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Options, SimpleMatcher};
+
+    fn read_part(reader: &mut BufReadSplitter<SimpleMatcher>, buf: &mut [u8]) -> Vec<u8> {
+        let mut part = Vec::new();
+        loop {
+            let sz = reader.read(buf).unwrap();
+            if sz == 0 {
+                break;
+            }
+            part.extend_from_slice(&buf[..sz]);
+        }
+        part
+    }
+
+    ///
+    /// Peek `n` bytes of each part, after reading `sz_before` bytes of it
+    fn peek_parts(
+        input: &[u8],
+        options: Options,
+        n: usize,
+        sz_before: usize,
+        sz_buf: usize,
+    ) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut input_reader = input;
+        let mut reader =
+            BufReadSplitter::new(&mut input_reader, SimpleMatcher::new(b"<SEP>"), options);
+        let mut buf = vec![0u8; sz_buf];
+        let mut parts = Vec::new();
+        while reader.next().unwrap() {
+            let mut before = vec![0u8; sz_before];
+            let sz = reader.read(&mut before).unwrap();
+            before.truncate(sz);
+            let peeked = reader.peek(n).unwrap().to_vec();
+            // Peeking again gives the same bytes
+            assert_eq!(reader.peek(n).unwrap(), peeked);
+            before.extend(read_part(&mut reader, &mut buf));
+            parts.push((peeked, before));
+        }
+        parts
+    }
+
+    fn small_options() -> Options {
+        Options::default()
+            .set_reserve_sz_to_match(2)
+            .set_extend_buffer_additionnal_sz(1)
+            .clone()
+    }
+
+    #[test]
+    fn test_sniff() {
+        let input = b"\x89PNG\r\n<SEP>{\"a\":1}<SEP>te<SEP>xt<SEx<SEP>";
+        for sz in 1..20 {
+            for n in [4, 100] {
+                let parts = peek_parts(input, small_options(), n, 0, sz);
+                let expected: Vec<&[u8]> =
+                    vec![b"\x89PNG\r\n", b"{\"a\":1}", b"te", b"xt<SEx", b""];
+                assert_eq!(parts.len(), expected.len(), "Case buf sz={sz} n={n}");
+                for ((peeked, part), expected) in parts.iter().zip(expected) {
+                    assert_eq!(part, expected, "Case buf sz={sz} n={n}");
+                    assert_eq!(
+                        peeked,
+                        &expected[..n.min(expected.len())],
+                        "Case buf sz={sz} n={n}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_after_read() {
+        let input = b"abcdef<SEP>ghi";
+        for sz in 1..20 {
+            let parts = peek_parts(input, small_options(), 3, 2, sz);
+            assert_eq!(
+                parts,
+                vec![
+                    (b"cde".to_vec(), b"abcdef".to_vec()),
+                    (b"i".to_vec(), b"ghi".to_vec()),
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let input = b"  abcdef <SEP>\tghi";
+        for sz in 1..20 {
+            let parts = peek_parts(input, small_options().set_trim(true).clone(), 4, 0, sz);
+            assert_eq!(
+                parts,
+                vec![
+                    (b"abcd".to_vec(), b"abcdef".to_vec()),
+                    (b"ghi".to_vec(), b"ghi".to_vec()),
+                ],
+                "Case buf sz={sz}"
+            );
+
+            let parts = peek_parts(
+                input,
+                small_options().set_fixed_size(Some(6)).clone(),
+                4,
+                1,
+                sz,
+            );
+            assert_eq!(
+                parts,
+                vec![
+                    (b" abc".to_vec(), b"  abcd".to_vec()),
+                    (b"f <S".to_vec(), b"ef <SE".to_vec()),
+                    (b">\tgh".to_vec(), b"P>\tghi".to_vec()),
+                ],
+                "Case buf sz={sz}"
+            );
+
+            let parts = peek_parts(
+                input,
+                small_options().set_limit_read(Some(3)).clone(),
+                4,
+                0,
+                sz,
+            );
+            assert_eq!(
+                parts,
+                vec![
+                    (b"  a".to_vec(), b"  a".to_vec()),
+                    (b"\tgh".to_vec(), b"\tgh".to_vec())
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests_read_vectored {
+    use std::io::{IoSliceMut, Read};
+
+    use buf_read_splitter::{BufReadSplitter, Options, SimpleMatcher};
+
+    #[test]
+    fn test_scatter() {
+        // A header of 4 bytes followed by a payload, for each frame
+        let input = "HDR1payload one<SEP>HDR2two<SEP>HDR3";
+        for sz_ext in 1..8 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<SEP>"),
+                Options::default()
+                    .set_reserve_sz_to_match(2)
+                    .set_extend_buffer_additionnal_sz(sz_ext)
+                    .clone(),
+            );
+            let mut frames = Vec::new();
+            while reader.next().unwrap() {
+                let mut header = [0u8; 4];
+                let mut payload = [0u8; 20];
+                let sz = reader
+                    .read_vectored(&mut [
+                        IoSliceMut::new(&mut header),
+                        IoSliceMut::new(&mut payload),
+                    ])
+                    .unwrap();
+                frames.push((
+                    sz,
+                    String::from_utf8_lossy(&header).to_string(),
+                    String::from_utf8_lossy(&payload[..sz - 4]).to_string(),
+                ));
+                assert_eq!(
+                    reader.read(&mut payload).unwrap(),
+                    0,
+                    "Case ext sz={sz_ext}"
+                );
+            }
+            assert_eq!(
+                frames,
+                vec![
+                    (15, "HDR1".to_string(), "payload one".to_string()),
+                    (7, "HDR2".to_string(), "two".to_string()),
+                    (4, "HDR3".to_string(), "".to_string()),
+                ],
+                "Case ext sz={sz_ext}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_scatter_partial() {
+        // The part is longer than the buffers, the rest is read after
+        let input = "abcdefghij<SEP>k";
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"<SEP>"),
+            Options::default(),
+        );
+        assert!(reader.next().unwrap());
+        let mut first = [0u8; 2];
+        let mut empty = [0u8; 0];
+        let mut second = [0u8; 3];
+        let sz = reader
+            .read_vectored(&mut [
+                IoSliceMut::new(&mut first),
+                IoSliceMut::new(&mut empty),
+                IoSliceMut::new(&mut second),
+            ])
+            .unwrap();
+        assert_eq!(sz, 5);
+        assert_eq!(&first, b"ab");
+        assert_eq!(&second, b"cde");
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "fghij");
+        assert!(reader.next().unwrap());
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "fghijk");
+    }
+
+    #[test]
+    fn test_read_exact() {
+        let input = "12345<SEP>123<SEP>end";
+        for sz_ext in 1..8 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<SEP>"),
+                Options::default()
+                    .set_reserve_sz_to_match(2)
+                    .set_extend_buffer_additionnal_sz(sz_ext)
+                    .clone(),
+            );
+            let mut buf = [0u8; 4];
+
+            // The part is long enough
+            assert!(reader.next().unwrap());
+            reader.read_exact(&mut buf).unwrap();
+            assert_eq!(&buf, b"1234", "Case ext sz={sz_ext}");
+
+            // The part ends before the buffer is filled : the separator is not read
+            assert!(reader.next().unwrap());
+            let err = reader.read_exact(&mut buf).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                std::io::ErrorKind::UnexpectedEof,
+                "Case ext sz={sz_ext}"
+            );
+            assert!(reader.next().unwrap(), "Case ext sz={sz_ext}");
+            let mut rest = String::new();
+            reader.read_to_string(&mut rest).unwrap();
+            assert_eq!(rest, "end", "Case ext sz={sz_ext}");
+        }
+    }
+}
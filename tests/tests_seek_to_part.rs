@@ -0,0 +1,289 @@
+#[cfg(test)]
+mod tests_seek_to_part {
+    use std::io::{Cursor, Read, Seek, SeekFrom};
+
+    use buf_read_splitter::{
+        BufReadSplitter, Matcher, MboxMatcher, Options, SimpleMatcher, SparseIndex, Utf16Matcher,
+    };
+
+    fn read_part<M: Matcher>(reader: &mut BufReadSplitter<M>) -> String {
+        let mut part = String::new();
+        reader.read_to_string(&mut part).unwrap();
+        part
+    }
+
+    fn all_parts<M: Matcher>(input: &str, matcher: M, options: Options) -> Vec<String> {
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(&mut input_reader, matcher, options);
+        let mut parts = Vec::new();
+        while reader.next().unwrap() {
+            parts.push(read_part(&mut reader));
+        }
+        parts
+    }
+
+    fn input() -> String {
+        (0..20)
+            .map(|i| format!("part {i}<SEP>"))
+            .collect::<String>()
+            + "last"
+    }
+
+    fn small_options() -> Options {
+        Options::default()
+            .set_reserve_sz_to_match(2)
+            .set_extend_buffer_additionnal_sz(1)
+            .clone()
+    }
+
+    #[test]
+    fn test_seek_to_part() {
+        let input = input();
+        let expected = all_parts(&input, SimpleMatcher::new(b"<SEP>"), small_options());
+        assert_eq!(expected.len(), 21);
+        for opt_interval in [None, Some(1), Some(3), Some(50)] {
+            let mut cursor = Cursor::new(input.as_bytes());
+            let mut reader = BufReadSplitter::new_seekable(
+                &mut cursor,
+                SimpleMatcher::new(b"<SEP>"),
+                small_options(),
+            );
+            if let Some(interval) = opt_interval {
+                reader.set_sparse_index(SparseIndex::new(interval));
+            }
+            for n in [5, 2, 2, 20, 0, 7, 8, 19, 1, 12, 3] {
+                assert!(
+                    reader.seek_to_part(n).unwrap(),
+                    "Case n={n} {opt_interval:?}"
+                );
+                // The part can be partly read before the next seek
+                let mut buf = [0u8; 2];
+                let sz = reader.read(&mut buf).unwrap();
+                assert_eq!(
+                    &buf[..sz],
+                    &expected[n].as_bytes()[..2],
+                    "Case n={n} {opt_interval:?}"
+                );
+                if n % 2 == 0 {
+                    let rest = read_part(&mut reader);
+                    assert_eq!(
+                        format!("{}{rest}", &expected[n][..2]),
+                        expected[n],
+                        "Case n={n} {opt_interval:?}"
+                    );
+                }
+            }
+            // The parts after are read as usual
+            assert!(reader.next().unwrap());
+            assert_eq!(read_part(&mut reader), expected[4]);
+            assert!(!reader.seek_to_part(21).unwrap());
+            assert!(!reader.next().unwrap());
+            assert!(reader.seek_to_part(0).unwrap());
+            assert_eq!(read_part(&mut reader), expected[0]);
+        }
+    }
+
+    #[test]
+    fn test_sparse_index() {
+        let input = input();
+        let mut cursor = Cursor::new(input.as_bytes());
+        let mut reader = BufReadSplitter::new_seekable(
+            &mut cursor,
+            SimpleMatcher::new(b"<SEP>"),
+            Options::default(),
+        );
+        reader.set_sparse_index(SparseIndex::new(4));
+        let mut offsets = Vec::new();
+        let mut n = 0;
+        while reader.next().unwrap() {
+            if n % 4 == 0 {
+                offsets.push(reader.part_offset());
+            }
+            n += 1;
+        }
+        assert_eq!(reader.sparse_index().unwrap().offsets(), offsets);
+        assert_eq!(offsets, vec![0, 44, 88, 134, 182, 230]);
+
+        // The index is not extended twice
+        assert!(reader.seek_to_part(3).unwrap());
+        assert_eq!(reader.count_remaining_parts().unwrap(), 17);
+        assert_eq!(reader.sparse_index().unwrap().offsets(), offsets);
+    }
+
+    #[test]
+    fn test_save_load() {
+        let input = input();
+        let mut cursor = Cursor::new(input.as_bytes());
+        let mut reader = BufReadSplitter::new_seekable(
+            &mut cursor,
+            SimpleMatcher::new(b"<SEP>"),
+            Options::default(),
+        );
+        reader.set_sparse_index(SparseIndex::new(5));
+        reader.count_remaining_parts().unwrap();
+        let mut saved = Vec::new();
+        reader.sparse_index().unwrap().save(&mut saved).unwrap();
+
+        let index = SparseIndex::load(&mut &saved[..]).unwrap();
+        assert_eq!(&index, reader.sparse_index().unwrap());
+        assert_eq!(index.interval(), 5);
+
+        // Another splitter goes directly to the closest part of the index
+        let mut cursor = Cursor::new(input.as_bytes());
+        let mut reader = BufReadSplitter::new_seekable(
+            &mut cursor,
+            SimpleMatcher::new(b"<SEP>"),
+            Options::default(),
+        );
+        reader.set_sparse_index(index);
+        assert!(reader.seek_to_part(16).unwrap());
+        assert_eq!(read_part(&mut reader), "part 16");
+        assert_eq!(reader.part_offset(), 182);
+
+        assert!(SparseIndex::load(&mut &b"BRSX"[..]).is_err());
+        assert!(SparseIndex::load(&mut &saved[..saved.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_stream_not_at_start() {
+        let input = format!("header{}", input());
+        let mut cursor = Cursor::new(input.as_bytes());
+        cursor.seek(SeekFrom::Start(6)).unwrap();
+        let mut reader = BufReadSplitter::new_seekable(
+            &mut cursor,
+            SimpleMatcher::new(b"<SEP>"),
+            small_options(),
+        );
+        reader.set_sparse_index(SparseIndex::new(2));
+        assert!(reader.seek_to_part(9).unwrap());
+        assert_eq!(read_part(&mut reader), "part 9");
+        assert!(reader.seek_to_part(0).unwrap());
+        assert_eq!(read_part(&mut reader), "part 0");
+        assert!(reader.seek_to_part(4).unwrap());
+        assert_eq!(read_part(&mut reader), "part 4");
+        assert_eq!(reader.part_offset(), 44);
+    }
+
+    #[test]
+    fn test_options() {
+        let input = "<SEP>a<SEP><SEP> b <SEP>c<SEP><SEP>d";
+        for (case, options) in [
+            small_options().set_skip_empty_parts(true).clone(),
+            small_options().set_trim(true).clone(),
+            small_options().set_fixed_size(Some(3)).clone(),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let expected = all_parts(input, SimpleMatcher::new(b"<SEP>"), options.clone());
+            let mut cursor = Cursor::new(input.as_bytes());
+            let mut reader =
+                BufReadSplitter::new_seekable(&mut cursor, SimpleMatcher::new(b"<SEP>"), options);
+            reader.set_sparse_index(SparseIndex::new(2));
+            reader.count_remaining_parts().unwrap();
+
+            // Seek each part backward, the parts are counted like `next` returns them
+            for n in (0..expected.len() + 2).rev() {
+                if n < expected.len() {
+                    assert!(reader.seek_to_part(n).unwrap(), "Case {case} n={n}");
+                    assert_eq!(read_part(&mut reader), expected[n], "Case {case} n={n}");
+                } else {
+                    assert!(!reader.seek_to_part(n).unwrap(), "Case {case} n={n}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_skip_empty_parts() {
+        let input = "a<SEP><SEP>b<SEP>c<SEP><SEP>d<SEP>e";
+        let expected = ["a", "b", "c", "d", "e"];
+        let mut cursor = Cursor::new(input.as_bytes());
+        let mut reader = BufReadSplitter::new_seekable(
+            &mut cursor,
+            SimpleMatcher::new(b"<SEP>"),
+            small_options().set_skip_empty_parts(true).clone(),
+        );
+        reader.set_sparse_index(SparseIndex::new(2));
+        // The same part whether the index is known or not
+        for n in [4, 3, 4, 3, 1, 2, 0, 4] {
+            assert!(reader.seek_to_part(n).unwrap(), "Case n={n}");
+            assert_eq!(read_part(&mut reader), expected[n], "Case n={n}");
+        }
+        assert!(!reader.seek_to_part(5).unwrap());
+
+        // `skip_parts` counts the same
+        assert!(reader.seek_to_part(0).unwrap());
+        assert_eq!(reader.skip_parts(3).unwrap(), 3);
+        assert_eq!(read_part(&mut reader), "d");
+    }
+
+    #[test]
+    fn test_matcher_fn() {
+        let input = "h|a,b,c";
+        let expected = ["h", "a", "b", "c"];
+        let mut cursor = Cursor::new(input.as_bytes());
+        let mut reader =
+            BufReadSplitter::new_seekable(&mut cursor, SimpleMatcher::new(b"|"), small_options());
+        reader.set_matcher_fn(|_, _| SimpleMatcher::new(b","));
+        reader.set_sparse_index(SparseIndex::new(1));
+        // The first part is split again with the matcher given to `new`
+        for n in [0, 2, 0, 1, 3, 0, 0] {
+            assert!(reader.seek_to_part(n).unwrap(), "Case n={n}");
+            assert_eq!(read_part(&mut reader), expected[n], "Case n={n}");
+        }
+        assert!(reader.next().unwrap());
+        assert_eq!(read_part(&mut reader), "a");
+    }
+
+    #[test]
+    fn test_mbox() {
+        let input = "From a\nbody a\nFrom b\nbody b\n\nFrom c\nbody c\n";
+        let expected = all_parts(input, MboxMatcher::new(), Options::default());
+        assert_eq!(expected.len(), 4);
+        let mut cursor = Cursor::new(input.as_bytes());
+        let mut reader =
+            BufReadSplitter::new_seekable(&mut cursor, MboxMatcher::new(), Options::default());
+        reader.set_sparse_index(SparseIndex::new(1));
+        for n in [2, 0, 3, 1, 2] {
+            assert!(reader.seek_to_part(n).unwrap());
+            assert_eq!(read_part(&mut reader), expected[n], "Case n={n}");
+        }
+    }
+
+    #[test]
+    fn test_utf16() {
+        let mut input = vec![0xFF, 0xFE]; // BOM
+        for unit in "one;two;three;four".encode_utf16() {
+            input.extend_from_slice(&unit.to_le_bytes());
+        }
+        let mut cursor = Cursor::new(&input[..]);
+        let mut reader =
+            BufReadSplitter::new_seekable(&mut cursor, Utf16Matcher::new(";"), small_options());
+        reader.set_sparse_index(SparseIndex::new(1));
+        let mut parts = Vec::new();
+        for n in [3, 1, 0, 2] {
+            assert!(reader.seek_to_part(n).unwrap());
+            let mut part = Vec::new();
+            reader.read_to_end(&mut part).unwrap();
+            parts.push(part.len());
+        }
+        assert_eq!(parts, vec![8, 6, 8, 10]);
+    }
+
+    #[test]
+    fn test_not_seekable() {
+        let input = input();
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"<SEP>"),
+            Options::default(),
+        );
+        assert!(reader.seek_to_part(2).is_err());
+        // Going forward doesn't need to seek
+        assert!(reader.next().unwrap());
+        assert!(reader.seek_to_part(2).unwrap());
+        assert_eq!(read_part(&mut reader), "part 2");
+    }
+}
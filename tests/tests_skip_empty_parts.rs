@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests_skip_empty_parts {
+    use std::io::Read;
+
+    use buf_read_splitter::{
+        AllEndOfLineMatcher, BufReadSplitter, Matcher, Options, SimpleMatcher, Statistics,
+    };
+
+    fn split<T: Matcher>(
+        input: &str,
+        matcher: T,
+        sz_buf: usize,
+    ) -> (Vec<(u64, String)>, Statistics) {
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            matcher,
+            Options::default()
+                .set_reserve_sz_to_match(2)
+                .set_extend_buffer_additionnal_sz(1)
+                .set_skip_empty_parts(true)
+                .clone(),
+        );
+        let mut parts = Vec::new();
+        let mut buf = vec![0u8; sz_buf];
+        while reader.next().unwrap() {
+            let mut part = String::new();
+            loop {
+                let sz = reader.read(&mut buf).unwrap();
+                if sz == 0 {
+                    break;
+                }
+                part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+            }
+            parts.push((reader.part_offset(), part));
+        }
+        (parts, reader.statistics())
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let input = "\n\r\nFirst\n\n\n\rSecond\r\n\r\nT\n\n";
+        for sz in 1..40 {
+            let (parts, stats) = split(input, AllEndOfLineMatcher::new(), sz);
+            assert_eq!(
+                parts,
+                vec![
+                    (3, "First".to_string()),
+                    (12, "Second".to_string()),
+                    (22, "T".to_string())
+                ],
+                "Case buf sz={sz}"
+            );
+            assert_eq!(stats.skipped_empty_parts, 8, "Case buf sz={sz}");
+        }
+    }
+
+    #[test]
+    fn test_consecutive_separators() {
+        let input = "<SEP><SEP>one<SEP><SEP><SEP>two<SEP>t<SEP>";
+        for sz in 1..40 {
+            let (parts, stats) = split(input, SimpleMatcher::new(b"<SEP>"), sz);
+            assert_eq!(
+                parts,
+                vec![
+                    (10, "one".to_string()),
+                    (28, "two".to_string()),
+                    (36, "t".to_string())
+                ],
+                "Case buf sz={sz}"
+            );
+            assert_eq!(stats.skipped_empty_parts, 5, "Case buf sz={sz}");
+        }
+    }
+
+    #[test]
+    fn test_only_separators() {
+        for sz in 1..10 {
+            let (parts, stats) = split("\n\n\r\n", AllEndOfLineMatcher::new(), sz);
+            assert!(parts.is_empty(), "Case buf sz={sz}");
+            assert_eq!(stats.skipped_empty_parts, 4, "Case buf sz={sz}");
+        }
+        let (parts, stats) = split("", AllEndOfLineMatcher::new(), 10);
+        assert!(parts.is_empty());
+        assert_eq!(stats.skipped_empty_parts, 0);
+    }
+
+    #[test]
+    fn test_using_next_part() {
+        for sz in 1..20 {
+            let input = "a\n\n\nb\n\nc";
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                AllEndOfLineMatcher::new(),
+                Options::default().set_skip_empty_parts(true).clone(),
+            );
+            let mut buf = vec![0u8; sz];
+            let mut text = String::new();
+            loop {
+                // Partially read the part, the remaining is skipped
+                let sz_read = reader.read(&mut buf[..1]).unwrap();
+                text.push_str(&String::from_utf8_lossy(&buf[..sz_read]));
+                if reader.next_part().unwrap().is_none() {
+                    break;
+                }
+                text.push('.');
+            }
+            assert_eq!(text, "a.b.c", "Case buf sz={sz}");
+        }
+    }
+}
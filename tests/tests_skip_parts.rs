@@ -0,0 +1,271 @@
+#[cfg(test)]
+mod tests_skip_parts {
+    use std::io::{ErrorKind, Read};
+
+    use buf_read_splitter::{AllEndOfLineMatcher, BufReadSplitter, Options, SimpleMatcher};
+
+    ///
+    /// Return a `WouldBlock` error before each chunk of `sz_chunk` bytes
+    struct BlockingReader<'a> {
+        input: &'a [u8],
+        sz_chunk: usize,
+        blocked: bool,
+    }
+    impl Read for BlockingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.blocked = !self.blocked;
+            if self.blocked && !self.input.is_empty() {
+                return Err(ErrorKind::WouldBlock.into());
+            }
+            let sz = self.sz_chunk.min(buf.len());
+            self.input.read(&mut buf[..sz])
+        }
+    }
+
+    ///
+    /// Call `f` again while it returns a `WouldBlock` error
+    fn retry<T>(mut f: impl FnMut() -> Result<T, Box<dyn std::error::Error>>) -> T {
+        loop {
+            match f() {
+                Ok(res) => return res,
+                Err(err) => assert_eq!(
+                    err.downcast_ref::<std::io::Error>().unwrap().kind(),
+                    ErrorKind::WouldBlock
+                ),
+            }
+        }
+    }
+
+    fn read_part(reader: &mut BufReadSplitter<SimpleMatcher>) -> String {
+        let mut part = String::new();
+        reader.read_to_string(&mut part).unwrap();
+        part
+    }
+
+    fn small_options() -> Options {
+        Options::default()
+            .set_reserve_sz_to_match(2)
+            .set_extend_buffer_additionnal_sz(1)
+            .clone()
+    }
+
+    #[test]
+    fn test_skip_parts() {
+        let input = "p0<SEP>p1<SEP>p2<x<SEP>p3<SEP><SEP>p5<SEP>p6";
+        for options in [small_options(), Options::default()] {
+            let mut input_reader = input.as_bytes();
+            let mut reader =
+                BufReadSplitter::new(&mut input_reader, SimpleMatcher::new(b"<SEP>"), options);
+            assert_eq!(reader.skip_parts(0).unwrap(), 0);
+            assert_eq!(reader.skip_parts(1).unwrap(), 1);
+            assert_eq!(read_part(&mut reader), "p0");
+            assert_eq!(reader.skip_parts(3).unwrap(), 3);
+            assert_eq!(read_part(&mut reader), "p3");
+            // The current part is partly read
+            assert_eq!(reader.skip_parts(2).unwrap(), 2);
+            let mut buf = [0u8; 1];
+            assert_eq!(reader.read(&mut buf).unwrap(), 1);
+            assert_eq!(reader.skip_parts(1).unwrap(), 1);
+            assert_eq!(read_part(&mut reader), "p6");
+            assert_eq!(reader.skip_parts(5).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn test_skip_to_end() {
+        let input = "p0<SEP>p1<SEP>p2";
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"<SEP>"),
+            small_options(),
+        );
+        assert_eq!(reader.skip_parts(10).unwrap(), 3);
+        assert!(!reader.next().unwrap());
+    }
+
+    #[test]
+    fn test_count_remaining_parts() {
+        let input = "a\r\nb\rc\n\r\n\rd\r";
+        for sz_ext in 1..8 {
+            let options = Options::default()
+                .set_reserve_sz_to_match(2)
+                .set_extend_buffer_additionnal_sz(sz_ext)
+                .clone();
+            // Reference count by reading the parts
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                AllEndOfLineMatcher::new(),
+                options.clone(),
+            );
+            let mut expected = 0;
+            let mut buf = [0u8; 3];
+            while reader.next().unwrap() {
+                while reader.read(&mut buf).unwrap() > 0 {}
+                expected += 1;
+            }
+            assert_eq!(expected, 7);
+
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                AllEndOfLineMatcher::new(),
+                options.clone(),
+            );
+            assert_eq!(
+                reader.count_remaining_parts().unwrap(),
+                expected,
+                "Case ext sz={sz_ext}"
+            );
+
+            let mut input_reader = input.as_bytes();
+            let mut reader =
+                BufReadSplitter::new(&mut input_reader, AllEndOfLineMatcher::new(), options);
+            assert!(reader.next().unwrap());
+            assert_eq!(
+                reader.count_remaining_parts().unwrap(),
+                expected - 1,
+                "Case ext sz={sz_ext}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_options() {
+        let input = "<SEP>abc<SEP><SEP>defgh<SEP>";
+        let count = |options: Options| {
+            let mut input_reader = input.as_bytes();
+            let mut reader =
+                BufReadSplitter::new(&mut input_reader, SimpleMatcher::new(b"<SEP>"), options);
+            reader.count_remaining_parts().unwrap()
+        };
+        assert_eq!(count(small_options()), 5);
+        assert_eq!(count(small_options().set_skip_empty_parts(true).clone()), 2);
+        assert_eq!(count(small_options().set_fixed_size(Some(4)).clone()), 7);
+    }
+
+    #[test]
+    fn test_large_input() {
+        let mut input = Vec::new();
+        for i in 0..100_000 {
+            input.extend_from_slice(format!("line {i}\n").as_bytes());
+        }
+        let mut input_reader = &input[..];
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"\n"),
+            Options::default(),
+        );
+        assert_eq!(reader.skip_parts(99_999).unwrap(), 99_999);
+        assert_eq!(read_part(&mut reader), "line 99998");
+        assert_eq!(reader.count_remaining_parts().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_truncated_exact_part() {
+        let input = "len=10|abc";
+        let mut input_reader = input.as_bytes();
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            SimpleMatcher::new(b"|"),
+            Options::default(),
+        );
+        assert!(reader.next().unwrap());
+        assert!(reader.next_part_exact(10).unwrap().is_some());
+        assert!(reader.skip_parts(1).is_err());
+    }
+
+    #[test]
+    fn test_read_sizes() {
+        ///
+        /// Keep the largest size asked to read
+        struct SizeReader<'a> {
+            input: &'a [u8],
+            sz_max: usize,
+        }
+        impl Read for SizeReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.sz_max = self.sz_max.max(buf.len());
+                self.input.read(buf)
+            }
+        }
+        let input = format!("{}<SEP>{}<SEP>end", "a".repeat(1000), "b".repeat(1000));
+        for (options, sz_max) in [
+            (small_options(), 16), // The extend buffer grows to hold the separator
+            (
+                small_options()
+                    .set_adaptive_extend_buffer_max_sz(Some(64))
+                    .clone(),
+                64,
+            ),
+        ] {
+            let mut input_reader = SizeReader {
+                input: input.as_bytes(),
+                sz_max: 0,
+            };
+            let mut reader =
+                BufReadSplitter::new(&mut input_reader, SimpleMatcher::new(b"<SEP>"), options);
+            assert_eq!(reader.skip_parts(3).unwrap(), 3);
+            drop(reader);
+            // The reads stay in the sizes of the options
+            assert!(input_reader.sz_max <= sz_max, "{}", input_reader.sz_max);
+        }
+    }
+
+    ///
+    /// Read the current part, again after each `WouldBlock` error
+    fn read_part_retry(reader: &mut BufReadSplitter<SimpleMatcher>) -> String {
+        let mut part = Vec::new();
+        let mut buf = [0u8; 3];
+        loop {
+            let sz = retry(|| Ok(reader.read(&mut buf)?));
+            if sz == 0 {
+                return String::from_utf8(part).unwrap();
+            }
+            part.extend_from_slice(&buf[..sz]);
+        }
+    }
+
+    #[test]
+    fn test_would_block() {
+        let input = "p0<SEP>p1<SEP>p2<SEP>p3<SEP>p4<SEP>p5<SEP>p6";
+        for sz_chunk in 1..10 {
+            let mut input_reader = BlockingReader {
+                input: input.as_bytes(),
+                sz_chunk,
+                blocked: false,
+            };
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<SEP>"),
+                small_options(),
+            );
+            assert_eq!(
+                retry(|| reader.skip_parts(3)),
+                3,
+                "Case 1 sz_chunk={sz_chunk}"
+            );
+            assert_eq!(
+                read_part_retry(&mut reader),
+                "p2",
+                "Case 2 sz_chunk={sz_chunk}"
+            );
+            assert_eq!(
+                retry(|| reader.skip_parts(2)),
+                2,
+                "Case 3 sz_chunk={sz_chunk}"
+            );
+            assert_eq!(
+                read_part_retry(&mut reader),
+                "p4",
+                "Case 4 sz_chunk={sz_chunk}"
+            );
+            assert_eq!(
+                retry(|| reader.count_remaining_parts()),
+                2,
+                "Case 5 sz_chunk={sz_chunk}"
+            );
+        }
+    }
+}
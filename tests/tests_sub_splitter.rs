@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests_sub_splitter {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Matcher, Options, SimpleMatcher};
+
+    fn read_part<T: Matcher>(reader: &mut BufReadSplitter<T>, buf: &mut [u8]) -> String {
+        let mut part = String::new();
+        loop {
+            let sz = reader.read(buf).unwrap();
+            if sz == 0 {
+                break;
+            }
+            part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+        }
+        part
+    }
+
+    #[test]
+    fn test_records_fields() {
+        let input = "a\tb\tc\n\nd\t\te\nf";
+        for sz_ext in 1..6 {
+            for sz in 1..20 {
+                let mut input_reader = input.as_bytes();
+                let mut reader = BufReadSplitter::new(
+                    &mut input_reader,
+                    SimpleMatcher::new(b"\n"),
+                    Options::default()
+                        .set_reserve_sz_to_match(1)
+                        .set_extend_buffer_additionnal_sz(sz_ext)
+                        .clone(),
+                );
+                let mut buf = vec![0u8; sz];
+                let mut records = Vec::new();
+                while reader.next().unwrap() {
+                    let mut fields = Vec::new();
+                    let mut sub = reader.sub_splitter(SimpleMatcher::new(b"\t"));
+                    while sub.next().unwrap() {
+                        fields.push(read_part(&mut sub, &mut buf));
+                    }
+                    records.push(fields);
+                }
+                assert_eq!(
+                    records,
+                    vec![vec!["a", "b", "c"], vec![], vec!["d", "", "e"], vec!["f"]],
+                    "Case buf sz={sz} ext sz={sz_ext}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_partially_read() {
+        let input = "a\tb\tc\nd\te\nf\tg";
+        for sz in 1..20 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"\n"),
+                Options::default(),
+            );
+            let mut buf = vec![0u8; sz];
+            let mut firsts = Vec::new();
+            while reader.next().unwrap() {
+                // Only the first field is read, the next record is reached anyway
+                let mut sub = reader.sub_splitter(SimpleMatcher::new(b"\t"));
+                assert!(sub.next().unwrap());
+                firsts.push(read_part(&mut sub, &mut buf));
+            }
+            assert_eq!(firsts, vec!["a", "d", "f"], "Case buf sz={sz}");
+        }
+    }
+
+    #[test]
+    fn test_three_levels() {
+        let input = "1,2;3|4;5,6";
+        for sz in 1..15 {
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"|"),
+                Options::default(),
+            );
+            let mut buf = vec![0u8; sz];
+            let mut text = String::new();
+            while reader.next().unwrap() {
+                let mut sub = reader.sub_splitter(SimpleMatcher::new(b";"));
+                while sub.next().unwrap() {
+                    let mut sub_sub = sub.sub_splitter(SimpleMatcher::new(b","));
+                    while sub_sub.next().unwrap() {
+                        text.push_str(&read_part(&mut sub_sub, &mut buf));
+                        text.push('.');
+                    }
+                    text.push(';');
+                }
+                text.push('|');
+            }
+            assert_eq!(text, "1.2.;3.;|4.;5.6.;|", "Case buf sz={sz}");
+        }
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests_trim {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Options, SimpleMatcher};
+
+    fn split(input: &str, options: &Options, sz_buf: usize) -> Vec<String> {
+        let mut input_reader = input.as_bytes();
+        let mut reader =
+            BufReadSplitter::new(&mut input_reader, SimpleMatcher::new(b"|"), options.clone());
+        let mut parts = Vec::new();
+        let mut buf = vec![0u8; sz_buf];
+        while reader.next().unwrap() {
+            let mut part = String::new();
+            loop {
+                let sz = reader.read(&mut buf).unwrap();
+                if sz == 0 {
+                    break;
+                }
+                part.push_str(&String::from_utf8_lossy(&buf[..sz]));
+            }
+            parts.push(part);
+        }
+        parts
+    }
+
+    #[test]
+    fn test_whitespaces() {
+        let input = "  one |two|\t two  and \r\n three \n|   |  \n";
+        let options = Options::default()
+            .set_reserve_sz_to_match(2)
+            .set_extend_buffer_additionnal_sz(1)
+            .set_trim(true)
+            .clone();
+        for sz in 1..50 {
+            assert_eq!(
+                split(input, &options, sz),
+                vec!["one", "two", "two  and \r\n three", "", ""],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_trim_bytes() {
+        let input = "--a-b--|-|c--";
+        let options = Options::default().set_trim_bytes(b"-").clone();
+        for sz in 1..20 {
+            assert_eq!(
+                split(input, &options, sz),
+                vec!["a-b", "", "c"],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_trim() {
+        let input = " a | b ";
+        let options = Options::default().set_trim(true).set_trim(false).clone();
+        assert_eq!(split(input, &options, 3), vec![" a ", " b "]);
+    }
+
+    #[test]
+    fn test_with_limit_read() {
+        let input = "   abc def   |   gh  ";
+        let options = Options::default()
+            .set_trim(true)
+            .set_limit_read(Some(5))
+            .clone();
+        for sz in 1..20 {
+            assert_eq!(
+                split(input, &options, sz),
+                vec!["abc d", "gh"],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_skip_empty_parts() {
+        let input = " | a |  \n |b|";
+        let options = Options::default()
+            .set_trim(true)
+            .set_skip_empty_parts(true)
+            .clone();
+        for sz in 1..20 {
+            assert_eq!(
+                split(input, &options, sz),
+                vec!["a", "b"],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests_unicode_line_matcher {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Options, UnicodeLineMatcher};
+
+    fn split(input: &[u8], sz_buf: usize) -> Vec<Vec<u8>> {
+        let mut input_reader = input;
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            UnicodeLineMatcher::new(),
+            Options::default()
+                .set_reserve_sz_to_match(2)
+                .set_extend_buffer_additionnal_sz(1)
+                .clone(),
+        );
+        let mut parts = Vec::new();
+        let mut buf = vec![0u8; sz_buf];
+        while reader.next().unwrap() {
+            let mut part = Vec::new();
+            loop {
+                let sz = reader.read(&mut buf).unwrap();
+                if sz == 0 {
+                    break;
+                }
+                part.extend_from_slice(&buf[..sz]);
+            }
+            parts.push(part);
+        }
+        parts
+    }
+
+    fn split_str(input: &str, sz_buf: usize) -> Vec<String> {
+        split(input.as_bytes(), sz_buf)
+            .into_iter()
+            .map(|p| String::from_utf8(p).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_all_breaks() {
+        let input = "a\r\nb\rc\nd\u{0B}e\u{0C}f\u{85}g\u{2028}h\u{2029}i";
+        for sz in 1..40 {
+            assert_eq!(
+                split_str(input, sz),
+                vec!["a", "b", "c", "d", "e", "f", "g", "h", "i"],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_consecutive_breaks() {
+        let input = "\r\r\n\n\r\u{2028}\u{2029}x\r";
+        for sz in 1..40 {
+            assert_eq!(
+                split_str(input, sz),
+                vec!["", "", "", "", "", "", "x", ""],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_breaks() {
+        // Characters sharing the first bytes of a line break
+        let input = "é\u{84}\u{2027}\u{202A}…\u{2029}end";
+        for sz in 1..40 {
+            assert_eq!(
+                split_str(input, sz),
+                vec!["é\u{84}\u{2027}\u{202A}…", "end"],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        // A line break just after an incomplete sequence
+        let input = b"a\xC2\nb\xE2\x80\r\nc\xE2\xC2\x85d\xE2\x80\xE2\x80\xA8e\xC2";
+        for sz in 1..40 {
+            assert_eq!(
+                split(input, sz),
+                vec![
+                    b"a\xC2".to_vec(),
+                    b"b\xE2\x80".to_vec(),
+                    b"c\xE2".to_vec(),
+                    b"d\xE2\x80".to_vec(),
+                    b"e\xC2".to_vec(),
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+}
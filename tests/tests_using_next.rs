@@ -54,6 +54,69 @@ mod tests_using_next {
         assert_eq!(count_part, 2);
     }
 
+    #[test]
+    fn test_part_offset() {
+        for sz in 1..20 {
+            let input = "one<SEP>two<SEP><SEP>three".to_string();
+            let mut input_reader = input.as_bytes();
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<SEP>"),
+                Options::default(),
+            );
+            let mut buf = vec![0u8; sz];
+            let mut offsets = Vec::new();
+            while reader.next().unwrap() {
+                offsets.push(reader.part_offset());
+                while reader.read(&mut buf).unwrap() > 0 {}
+            }
+            assert_eq!(offsets, vec![0, 8, 16, 21], "Case buf sz={sz}");
+        }
+    }
+
+    #[test]
+    fn test_large_parts() {
+        // Parts bigger than the buffer, with the first byte of the separator in the parts
+        let sizes = [1_000_000, 65_536, 65_535, 1, 0, 200_000];
+        let mut input = Vec::new();
+        for (i, sz) in sizes.iter().enumerate() {
+            // (SimpleMatcher doesn't find a separator just after its first byte, so the parts don't end by it)
+            input.extend((0..*sz).map(|n| {
+                if n % 7 == 0 && n + 1 != *sz {
+                    b'<'
+                } else {
+                    b'a' + i as u8
+                }
+            }));
+            input.extend_from_slice(b"<SEP>");
+        }
+        for sz_buf in [1_000, 65_536, 100_000] {
+            let mut input_reader = &input[..];
+            let mut reader = BufReadSplitter::new(
+                &mut input_reader,
+                SimpleMatcher::new(b"<SEP>"),
+                Options::default(),
+            );
+            let mut buf = vec![0u8; sz_buf];
+            let mut found = Vec::new();
+            while reader.next().unwrap() {
+                let mut sz_part = 0;
+                loop {
+                    let sz = reader.read(&mut buf).unwrap();
+                    if sz == 0 {
+                        break;
+                    }
+                    assert!(!buf[..sz].windows(5).any(|w| w == b"<SEP>"));
+                    sz_part += sz;
+                }
+                found.push(sz_part);
+            }
+            let mut expected = sizes.to_vec();
+            expected.push(0);
+            assert_eq!(found, expected, "Case buf sz={sz_buf}");
+        }
+    }
+
     #[test]
     fn test_common() {
         for i in 1..1000 {
@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::partialeq_to_none, clippy::collapsible_if)]
 mod tests_using_next_part {
     use std::io::Read;
 
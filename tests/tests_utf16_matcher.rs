@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests_utf16_matcher {
+    use std::io::Read;
+
+    use buf_read_splitter::{BufReadSplitter, Options, Utf16Matcher};
+
+    fn utf16(text: &str, big_endian: bool) -> Vec<u8> {
+        text.encode_utf16()
+            .flat_map(|u| {
+                if big_endian {
+                    u.to_be_bytes()
+                } else {
+                    u.to_le_bytes()
+                }
+            })
+            .collect()
+    }
+
+    fn split(input: &[u8], sz_buf: usize, matcher: Utf16Matcher) -> Vec<Vec<u8>> {
+        let mut input_reader = input;
+        let mut reader = BufReadSplitter::new(
+            &mut input_reader,
+            matcher,
+            Options::default()
+                .set_reserve_sz_to_match(2)
+                .set_extend_buffer_additionnal_sz(1)
+                .clone(),
+        );
+        let mut parts = Vec::new();
+        let mut buf = vec![0u8; sz_buf];
+        while reader.next().unwrap() {
+            let mut part = Vec::new();
+            loop {
+                let sz = reader.read(&mut buf).unwrap();
+                if sz == 0 {
+                    break;
+                }
+                part.extend_from_slice(&buf[..sz]);
+            }
+            parts.push(part);
+        }
+        parts
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = utf16("één\r\ndeux\r\n\r\ntrois €", false);
+        for sz in 1..40 {
+            let parts = split(&input, sz, Utf16Matcher::new("\r\n"));
+            assert_eq!(
+                parts,
+                vec![
+                    utf16("één", false),
+                    utf16("deux", false),
+                    vec![],
+                    utf16("trois €", false)
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_misaligned() {
+        // The bytes of "\r\n" (0D 00 0A 00) are found only across code units
+        let input = [0x41u8, 0x0D, 0x00, 0x0A, 0x00, 0x00, 0x42, 0x00];
+        for sz in 1..20 {
+            let parts = split(&input, sz, Utf16Matcher::new("\r\n"));
+            assert_eq!(parts, vec![input.to_vec()], "Case 1 buf sz={sz}");
+        }
+        // Once aligned, the separator is found
+        let input = [0x41u8, 0x00, 0x0D, 0x00, 0x0A, 0x00, 0x42, 0x00];
+        for sz in 1..20 {
+            let parts = split(&input, sz, Utf16Matcher::new("\r\n"));
+            assert_eq!(
+                parts,
+                vec![input[..2].to_vec(), input[6..].to_vec()],
+                "Case 2 buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_repeat() {
+        // The separator begins again inside a partial match
+        let input = utf16("x::::;y::;:::;z", false);
+        for sz in 1..40 {
+            let parts = split(&input, sz, Utf16Matcher::new("::;"));
+            assert_eq!(
+                parts,
+                vec![
+                    utf16("x::", false),
+                    utf16("y", false),
+                    utf16(":", false),
+                    utf16("z", false)
+                ],
+                "Case buf sz={sz}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bom() {
+        for big_endian in [false, true] {
+            let mut input = utf16("\u{FEFF}one|two", big_endian);
+            input.extend(utf16("|", big_endian));
+            let bom = utf16("\u{FEFF}", big_endian);
+            for sz in 1..20 {
+                for matcher in [Utf16Matcher::new("|"), Utf16Matcher::new_big_endian("|")] {
+                    let parts = split(&input, sz, matcher);
+                    let mut first = bom.clone();
+                    first.extend(utf16("one", big_endian));
+                    assert_eq!(
+                        parts,
+                        vec![first, utf16("two", big_endian), vec![]],
+                        "Case big_endian={big_endian} buf sz={sz}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_endianness() {
+        let input = utf16("one|two", true);
+        for sz in 1..20 {
+            let parts = split(&input, sz, Utf16Matcher::new_big_endian("|"));
+            assert_eq!(
+                parts,
+                vec![utf16("one", true), utf16("two", true)],
+                "Case 1 buf sz={sz}"
+            );
+            // Read as little-endian, the separator is not found
+            let parts = split(&input, sz, Utf16Matcher::new("|"));
+            assert_eq!(parts, vec![input.clone()], "Case 2 buf sz={sz}");
+        }
+    }
+}
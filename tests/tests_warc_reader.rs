@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests_warc_reader {
+    use std::io::Read;
+
+    use buf_read_splitter::{ReaderOptions, RecordError, WarcReader, WarcRecord};
+
+    fn record(id: &str, payload: &str) -> String {
+        format!(
+            "WARC/1.1\r\nWARC-Type: resource\r\nWARC-Record-ID: {id}\r\nContent-Length: {}\r\n\r\n{payload}\r\n\r\n",
+            payload.len()
+        )
+    }
+
+    #[test]
+    fn test_records() {
+        let input = record("<urn:1>", "Payload with\r\n\r\ninside")
+            + &record("<urn:2>", "")
+            + &record("<urn:3>", "Last one");
+        for sz in [1, 2, 3, 5, 10, 100] {
+            let mut input_reader = input.as_bytes();
+            let mut reader = WarcReader::new(
+                &mut input_reader,
+                ReaderOptions::default()
+                    .set_extend_buffer_additionnal_sz(sz)
+                    .clone(),
+            );
+
+            let rec = reader.next_record().unwrap().unwrap();
+            assert_eq!(rec.version, "WARC/1.1", "Case 1a");
+            assert_eq!(rec.get("warc-record-id"), Some("<urn:1>"), "Case 1b");
+            assert_eq!(rec.content_length, 22, "Case 1c");
+            assert_eq!(rec.offset, 0, "Case 1d");
+            let mut payload = String::new();
+            reader.read_to_string(&mut payload).unwrap();
+            assert_eq!(payload, "Payload with\r\n\r\ninside", "Case 1e");
+
+            let rec = reader.next_record().unwrap().unwrap();
+            assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:2>"), "Case 2a");
+            let mut payload = String::new();
+            reader.read_to_string(&mut payload).unwrap();
+            assert_eq!(payload, "", "Case 2b");
+
+            // Payload not read
+            let rec = reader.next_record().unwrap().unwrap();
+            assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:3>"), "Case 3");
+
+            assert!(reader.next_record().unwrap().is_none(), "Case 4");
+        }
+    }
+
+    #[test]
+    fn test_limit_record() {
+        let input =
+            record("<urn:1>", "one") + &record("<urn:too long>", "two") + &record("<urn:3>", "");
+        let mut input_reader = input.as_bytes();
+        let mut reader = WarcReader::new(
+            &mut input_reader,
+            ReaderOptions::default().set_limit_record(Some(73)).clone(),
+        );
+
+        let rec = reader.next_record().unwrap().unwrap();
+        assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:1>"), "Case 1a");
+        let mut payload = String::new();
+        reader.read_to_string(&mut payload).unwrap();
+        assert_eq!(payload, "one", "Case 1b");
+        assert!(is_malformed(reader.next_record(), 1), "Case 2");
+        let rec = reader.next_record().unwrap().unwrap();
+        assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:3>"), "Case 3");
+        assert!(reader.next_record().unwrap().is_none(), "Case 4");
+    }
+
+    #[test]
+    fn test_malformed() {
+        let input = "WARC/1.0\r\nWARC-Type: resource\r\n\r\n\r\n\r\n".to_string()
+            + &record("<urn:2>", "Second")
+            + "WARC/1.0\r\nContent-Length: 10\r\n\r\nShort";
+        let mut input_reader = input.as_bytes();
+        let mut reader = WarcReader::new(&mut input_reader, ReaderOptions::default());
+
+        assert!(
+            matches!(
+                reader.next_record(),
+                Err(RecordError::Malformed { index: 0, .. })
+            ),
+            "Case 1"
+        );
+        let rec = reader.next_record().unwrap().unwrap();
+        assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:2>"), "Case 2");
+
+        let rec = reader.next_record().unwrap().unwrap();
+        assert_eq!(rec.content_length, 10, "Case 3a");
+        let mut payload = Vec::new();
+        let err = reader.read_to_end(&mut payload).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof, "Case 3b");
+        assert_eq!(payload, b"Short", "Case 3c");
+
+        assert!(reader.next_record().unwrap().is_none(), "Case 4");
+    }
+
+    fn record_with_trailer(id: &str, payload: &str, trailer: &str) -> String {
+        let record = record(id, payload);
+        format!("{}{trailer}", &record[..record.len() - 4])
+    }
+
+    fn is_malformed(res: Result<Option<WarcRecord>, RecordError>, index: usize) -> bool {
+        matches!(res, Err(RecordError::Malformed { index: i, .. }) if i == index)
+    }
+
+    #[test]
+    fn test_bad_trailer() {
+        let input = record_with_trailer("<urn:1>", "one", "\r\n")
+            + &record_with_trailer("<urn:2>", "", "\r\n\r\nextra\r\n")
+            + &record("<urn:3>", "three")
+            + &record_with_trailer("<urn:4>", "four", "\r\n\r");
+        for sz in [1, 2, 3, 5, 10, 100] {
+            let mut input_reader = input.as_bytes();
+            let mut reader = WarcReader::new(
+                &mut input_reader,
+                ReaderOptions::default()
+                    .set_extend_buffer_additionnal_sz(sz)
+                    .clone(),
+            );
+
+            let rec = reader.next_record().unwrap().unwrap();
+            assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:1>"), "Case 1a");
+            let mut payload = String::new();
+            reader.read_to_string(&mut payload).unwrap();
+            assert_eq!(payload, "one", "Case 1b");
+            assert!(is_malformed(reader.next_record(), 0), "Case 1c sz={sz}");
+
+            let rec = reader.next_record().unwrap().unwrap();
+            assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:2>"), "Case 2a");
+            assert!(is_malformed(reader.next_record(), 1), "Case 2b sz={sz}");
+
+            let rec = reader.next_record().unwrap().unwrap();
+            assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:3>"), "Case 3");
+
+            let rec = reader.next_record().unwrap().unwrap();
+            assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:4>"), "Case 4a");
+            assert!(is_malformed(reader.next_record(), 3), "Case 4b sz={sz}");
+            assert!(reader.next_record().unwrap().is_none(), "Case 5");
+        }
+    }
+
+    #[test]
+    fn test_bad_header_with_payload() {
+        let input =
+            "WARC/1.0\r\nWARC-Type: resource\r\n\r\nPayload with\r\n\r\nWARC-Type: inside\r\n\r\n"
+                .to_string()
+                + &record("<urn:2>", "two");
+        let mut input_reader = input.as_bytes();
+        let mut reader = WarcReader::new(&mut input_reader, ReaderOptions::default());
+
+        assert!(is_malformed(reader.next_record(), 0), "Case 1");
+        let rec = reader.next_record().unwrap().unwrap();
+        assert_eq!(rec.get("WARC-Record-ID"), Some("<urn:2>"), "Case 2a");
+        let mut payload = String::new();
+        reader.read_to_string(&mut payload).unwrap();
+        assert_eq!(payload, "two", "Case 2b");
+        assert!(reader.next_record().unwrap().is_none(), "Case 3");
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests_would_block {
+    use std::io::{ErrorKind, Read};
+
+    use buf_read_splitter::{
+        AllEndOfLineMatcher, BufReadSplitter, Matcher, Options, SimpleMatcher, UnicodeLineMatcher,
+    };
+
+    ///
+    /// Give the input by chunks, with an error before each chunk
+    struct BlockingReader<'a> {
+        input: &'a [u8],
+        sz_chunk: usize,
+        error: ErrorKind,
+        blocked: bool,
+    }
+    impl Read for BlockingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.blocked = !self.blocked;
+            if self.blocked && !self.input.is_empty() {
+                return Err(self.error.into());
+            }
+            let sz = self.sz_chunk.min(buf.len());
+            self.input.read(&mut buf[..sz])
+        }
+    }
+
+    fn is_would_block(err: &(dyn std::error::Error + 'static)) -> bool {
+        err.downcast_ref::<std::io::Error>()
+            .is_some_and(|err| err.kind() == ErrorKind::WouldBlock)
+    }
+
+    ///
+    /// Split the stream, calling again each function returning `WouldBlock`
+    fn split<M: Matcher>(
+        reader: &mut dyn Read,
+        matcher: M,
+        options: Options,
+        sz_buf: usize,
+    ) -> (Vec<String>, usize) {
+        let mut reader = BufReadSplitter::new(reader, matcher, options);
+        let mut parts = Vec::new();
+        let mut nb_would_block = 0;
+        let mut buf = vec![0u8; sz_buf];
+        loop {
+            match reader.next() {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(err) if is_would_block(err.as_ref()) => {
+                    nb_would_block += 1;
+                    continue;
+                }
+                Err(err) => panic!("{err}"),
+            }
+            let mut part = Vec::new();
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(sz) => part.extend_from_slice(&buf[..sz]),
+                    Err(err) if err.kind() == ErrorKind::WouldBlock => nb_would_block += 1,
+                    Err(err) => panic!("{err}"),
+                }
+            }
+            parts.push(String::from_utf8(part).unwrap());
+        }
+        (parts, nb_would_block)
+    }
+
+    fn check<M: Matcher, F: Fn() -> M>(input: &str, new_matcher: F, options: Options) {
+        for sz_buf in 1..12 {
+            let mut input_reader = input.as_bytes();
+            let (expected, _) = split(&mut input_reader, new_matcher(), options.clone(), sz_buf);
+            for sz_chunk in 1..8 {
+                for error in [ErrorKind::WouldBlock, ErrorKind::Interrupted] {
+                    let mut input_reader = BlockingReader {
+                        input: input.as_bytes(),
+                        sz_chunk,
+                        error,
+                        blocked: false,
+                    };
+                    let (parts, nb_would_block) =
+                        split(&mut input_reader, new_matcher(), options.clone(), sz_buf);
+                    assert_eq!(
+                        parts, expected,
+                        "Case buf sz={sz_buf} chunk sz={sz_chunk} {error:?}"
+                    );
+                    if error == ErrorKind::Interrupted {
+                        assert_eq!(
+                            nb_would_block, 0,
+                            "Case buf sz={sz_buf} chunk sz={sz_chunk}"
+                        );
+                    } else {
+                        assert!(
+                            nb_would_block > 0,
+                            "Case buf sz={sz_buf} chunk sz={sz_chunk}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn small_options() -> Options {
+        Options::default()
+            .set_reserve_sz_to_match(2)
+            .set_extend_buffer_additionnal_sz(1)
+            .clone()
+    }
+
+    #[test]
+    fn test_simple_matcher() {
+        check(
+            "one<SEP>two<SE<SEP><SEP>three<S<SEP>",
+            || SimpleMatcher::new(b"<SEP>"),
+            small_options(),
+        );
+        check(
+            "one<SEP>two<SE<SEP><SEP>three<S",
+            || SimpleMatcher::new(b"<SEP>"),
+            Options::default(),
+        );
+    }
+
+    #[test]
+    fn test_end_of_line() {
+        check(
+            "a\r\nb\rc\n\r\n\r\rd\r",
+            AllEndOfLineMatcher::new,
+            small_options(),
+        );
+    }
+
+    #[test]
+    fn test_unicode_line() {
+        check(
+            "a\u{2028}b\r\u{85}\u{2029}\u{20AC}\r\nc\r",
+            UnicodeLineMatcher::new,
+            small_options(),
+        );
+    }
+
+    #[test]
+    fn test_skip_empty_parts() {
+        check(
+            "<SEP><SEP>one<SEP><SEP><SEP>two<SEP><SEP>",
+            || SimpleMatcher::new(b"<SEP>"),
+            small_options().set_skip_empty_parts(true).clone(),
+        );
+    }
+
+    #[test]
+    fn test_trim() {
+        check(
+            " one <SEP>  <SEP>\ttwo\t\t<SEP>",
+            || SimpleMatcher::new(b"<SEP>"),
+            small_options().set_trim(true).clone(),
+        );
+    }
+}